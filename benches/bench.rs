@@ -46,6 +46,28 @@ fn criterion_benchmark(c: &mut Criterion) {
         group.finish();
     }
 
+    {
+        let mut group = c.benchmark_group("oasiscap::v1dot2::Alert::from_str_assume_v1dot2");
+        group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+        for (name, data) in fixtures {
+            if !matches!(
+                oasiscap::Alert::from_str(data),
+                Ok(oasiscap::Alert::V1dot2(_))
+            ) {
+                continue;
+            }
+            group.throughput(Throughput::Bytes(data.len() as u64));
+            group.bench_with_input(BenchmarkId::from_parameter(name), data, |b, data| {
+                b.iter_batched(
+                    || data,
+                    |data| oasiscap::v1dot2::Alert::from_str_assume_v1dot2(data).unwrap(),
+                    BatchSize::LargeInput,
+                );
+            });
+        }
+        group.finish();
+    }
+
     {
         let mut group = c.benchmark_group("oasiscap::Alert::to_string");
         group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
@@ -59,6 +81,23 @@ fn criterion_benchmark(c: &mut Criterion) {
         group.finish();
     }
 
+    {
+        let mut group = c.benchmark_group("oasiscap::Alert::write_xml");
+        group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+        for (name, data) in fixtures {
+            let alert = oasiscap::Alert::from_str(data).unwrap();
+            group.throughput(Throughput::Bytes(alert.to_string().len() as u64));
+            group.bench_with_input(BenchmarkId::from_parameter(name), &alert, |b, alert| {
+                b.iter_batched(
+                    || alert,
+                    |alert| alert.write_xml(std::io::sink()).unwrap(),
+                    BatchSize::LargeInput,
+                );
+            });
+        }
+        group.finish();
+    }
+
     protobuf(c, &fixtures);
 }
 
@@ -62,6 +62,57 @@ impl Alert {
         }
     }
 
+    /// The `(sender, identifier, sent)` tuple which uniquely identifies this alert.
+    ///
+    /// CAP considers two alerts with the same sender, identifier, and sent time to be the same
+    /// message, which is also how a [`Reference`](crate::references::Reference) refers to an
+    /// alert. This is useful for discarding alerts which a feed delivers more than once.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot1_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.1 appendix A.1 */;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    ///
+    /// let mut seen = std::collections::HashSet::new();
+    /// assert!(seen.insert(alert.dedup_key()));
+    ///
+    /// // The same alert, even in a different CAP version, has the same key
+    /// let upgraded: oasiscap::Alert = alert.clone().into_latest().into();
+    /// assert!(!seen.insert(upgraded.dedup_key()));
+    /// ```
+    pub fn dedup_key(&self) -> (crate::id::Id, crate::id::Id, crate::DateTime) {
+        (
+            self.sender().clone(),
+            self.identifier().clone(),
+            self.sent(),
+        )
+    }
+
+    /// The `(sender, identifier)` of each alert this alert references, in order.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot3.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.3 */;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    ///
+    /// let ids = alert.referenced_ids();
+    /// assert_eq!(ids.len(), 1);
+    /// assert_eq!(*ids[0].0, "trinet@caltech.edu");
+    /// assert_eq!(*ids[0].1, "TRI13970876.1");
+    /// ```
+    pub fn referenced_ids(&self) -> Vec<(&crate::id::Id, &crate::id::Id)> {
+        let references = match self {
+            Alert::V1dot0(alert) => alert.references.as_ref(),
+            Alert::V1dot1(alert) => alert.references.as_ref(),
+            Alert::V1dot2(alert) => alert.references.as_ref(),
+        };
+        references
+            .into_iter()
+            .flatten()
+            .map(|reference| (&reference.sender, &reference.identifier))
+            .collect()
+    }
+
     /// Returns the XML namespace corresponding to the encapsulated CAP alert version.
     pub fn xml_namespace(&self) -> &'static str {
         match self {
@@ -121,6 +172,9 @@ impl Alert {
     /// let alert = alert.into_latest();
     /// assert_eq!(alert.info[0].certainty, oasiscap::v1dot2::Certainty::Likely);
     /// ```
+    ///
+    /// CAP v1.0 `password` is also discarded. Use `into_latest_with_report()` to find out which of
+    /// these changes were made to a particular alert.
     pub fn into_latest(self) -> crate::v1dot2::Alert {
         match self {
             Alert::V1dot0(alert) => alert.into(),
@@ -128,13 +182,509 @@ impl Alert {
             Alert::V1dot2(alert) => alert,
         }
     }
+
+    /// The most severe `Info` in this alert, upgraded to CAP v1.2 as necessary.
+    ///
+    /// See [`v1dot2::Alert::primary_info`] for how the `Info` is selected.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot0_appendix_adot3.xml");
+    /// // let input: &str = /* CAP v1.0 appendix A.3 */;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    /// let info = alert.primary_info().unwrap();
+    /// assert_eq!(info.certainty, oasiscap::v1dot2::Certainty::Likely);
+    /// ```
+    pub fn primary_info(&self) -> Option<v1dot2::Info> {
+        match self {
+            Alert::V1dot2(alert) => alert.primary_info().cloned(),
+            other => other.clone().into_latest().primary_info().cloned(),
+        }
+    }
+
+    /// Count the parts of this alert, and collect its categories and response types, upgrading a
+    /// copy of it to CAP v1.2 as necessary.
+    ///
+    /// See [`v1dot2::Alert::summary`] for details.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot0_appendix_adot3.xml");
+    /// // let input: &str = /* CAP v1.0 appendix A.3 */;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    /// let summary = alert.summary();
+    /// assert_eq!(summary.info, 1);
+    /// assert_eq!(summary.circles, 1);
+    /// ```
+    pub fn summary(&self) -> v1dot2::AlertSummary {
+        match self {
+            Alert::V1dot2(alert) => alert.summary(),
+            other => other.clone().into_latest().summary(),
+        }
+    }
+
+    /// Iterate over every polygon and circle in this alert, across all of its `Info` blocks and
+    /// areas.
+    ///
+    /// Every CAP version shares the types in [`geo`](crate::geo), so no upgrade is needed. Each
+    /// area's polygons are yielded before its circles.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot0_appendix_adot3.xml");
+    /// // let input: &str = /* CAP v1.0 appendix A.3 */;
+    /// use oasiscap::geo::Shape;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    ///
+    /// let shapes = alert.shapes().collect::<Vec<_>>();
+    /// assert_eq!(shapes.len(), 1);
+    /// assert!(matches!(&shapes[0], Shape::Circle(circle) if circle.radius == 0.0));
+    /// ```
+    pub fn shapes(&self) -> impl Iterator<Item = crate::geo::Shape> + '_ {
+        use crate::geo::{Circle, Polygon, Shape};
+
+        let areas: Box<dyn Iterator<Item = (&[Polygon], &[Circle])> + '_> = match self {
+            Alert::V1dot0(alert) => Box::new(
+                alert
+                    .info
+                    .iter()
+                    .flat_map(|info| &info.areas)
+                    .map(|area| (area.polygons.as_slice(), area.circles.as_slice())),
+            ),
+            Alert::V1dot1(alert) => Box::new(
+                alert
+                    .info
+                    .iter()
+                    .flat_map(|info| &info.areas)
+                    .map(|area| (area.polygons.as_slice(), area.circles.as_slice())),
+            ),
+            Alert::V1dot2(alert) => Box::new(
+                alert
+                    .info
+                    .iter()
+                    .flat_map(|info| &info.areas)
+                    .map(|area| (area.polygons.as_slice(), area.circles.as_slice())),
+            ),
+        };
+
+        areas.flat_map(|(polygons, circles)| {
+            polygons
+                .iter()
+                .cloned()
+                .map(Shape::Polygon)
+                .chain(circles.iter().copied().map(Shape::Circle))
+        })
+    }
+
+    /// Returns `true` if any polygon or circle in this alert overlaps the box with corners `sw`
+    /// and `ne`.
+    ///
+    /// This is a cheap prefilter for `intersects_polygon()`; see
+    /// [`Shape::intersects_bbox`](crate::geo::Shape::intersects_bbox). Alerts which describe their
+    /// areas only by geocode never intersect.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// use oasiscap::geo::Point;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    ///
+    /// let point = |lat, lon| Point::new(lat, lon).unwrap();
+    /// assert!(alert.intersects_bbox(point(38.0, -121.0), point(39.0, -119.0)));
+    /// assert!(!alert.intersects_bbox(point(40.0, -121.0), point(41.0, -119.0)));
+    /// ```
+    pub fn intersects_bbox(&self, sw: crate::geo::Point, ne: crate::geo::Point) -> bool {
+        self.shapes().any(|shape| shape.intersects_bbox(sw, ne))
+    }
+
+    /// Returns `true` if any polygon or circle in this alert has any point in common with `filter`.
+    ///
+    /// See [`Shape::intersects_polygon`](crate::geo::Shape::intersects_polygon). Alerts which
+    /// describe their areas only by geocode never intersect.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// use oasiscap::geo::Polygon;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    ///
+    /// let county: Polygon = "38.5,-120.5 38.5,-119.9 38.9,-119.9 38.9,-120.5 38.5,-120.5"
+    ///     .parse()
+    ///     .unwrap();
+    /// assert!(alert.intersects_polygon(&county));
+    ///
+    /// let elsewhere: Polygon = "38.5,-119.5 38.5,-119.0 38.9,-119.0 38.5,-119.5".parse().unwrap();
+    /// assert!(!alert.intersects_polygon(&elsewhere));
+    /// ```
+    pub fn intersects_polygon(&self, filter: &crate::geo::Polygon) -> bool {
+        self.shapes().any(|shape| shape.intersects_polygon(filter))
+    }
+
+    /// The `Info` blocks whose language matches `lang`, upgraded to CAP v1.2 as necessary.
+    ///
+    /// See [`v1dot2::Alert::info_for_language`] for how languages are matched. The `Info` blocks
+    /// of a CAP v1.2 alert are borrowed, while only the matching blocks of an older alert are
+    /// upgraded.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot1_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.1 appendix A.1 */;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    /// let info = alert.info_for_language("en");
+    /// assert_eq!(info.len(), 1);
+    /// assert_eq!(info[0].event, "Homeland Security Advisory System Update");
+    /// ```
+    pub fn info_for_language(&self, lang: &str) -> Vec<std::borrow::Cow<'_, v1dot2::Info>> {
+        use std::borrow::Cow;
+        match self {
+            Alert::V1dot0(alert) => {
+                crate::language::select(&alert.info, |info| &info.language, lang)
+                    .into_iter()
+                    .map(|info| Cow::Owned(v1dot2::Info::from(v1dot1::Info::from(info.clone()))))
+                    .collect()
+            }
+            Alert::V1dot1(alert) => {
+                crate::language::select(&alert.info, |info| &info.language, lang)
+                    .into_iter()
+                    .map(|info| Cow::Owned(v1dot2::Info::from(info.clone())))
+                    .collect()
+            }
+            Alert::V1dot2(alert) => alert
+                .info_for_language(lang)
+                .into_iter()
+                .map(Cow::Borrowed)
+                .collect(),
+        }
+    }
+
+    /// The `Info` blocks which recommend `response_type`, upgraded to CAP v1.2 as necessary.
+    ///
+    /// As with `info_for_language()`, the `Info` blocks of a CAP v1.2 alert are borrowed, while
+    /// only the matching blocks of an older alert are upgraded. CAP v1.0 has no response types, so
+    /// a CAP v1.0 alert never has any matching `Info` blocks.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot1_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.1 appendix A.2 */;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    /// let info = alert.info_with_response_type(oasiscap::v1dot2::ResponseType::Shelter);
+    /// assert_eq!(info.len(), 1);
+    /// ```
+    pub fn info_with_response_type(
+        &self,
+        response_type: v1dot2::ResponseType,
+    ) -> Vec<std::borrow::Cow<'_, v1dot2::Info>> {
+        use std::borrow::Cow;
+        match self {
+            Alert::V1dot0(_) => Vec::new(),
+            Alert::V1dot1(alert) => alert
+                .info
+                .iter()
+                .filter(|info| {
+                    info.response_type
+                        .iter()
+                        .any(|r| v1dot2::ResponseType::from(*r) == response_type)
+                })
+                .map(|info| Cow::Owned(v1dot2::Info::from(info.clone())))
+                .collect(),
+            Alert::V1dot2(alert) => alert
+                .info_with_response_type(response_type)
+                .into_iter()
+                .map(Cow::Borrowed)
+                .collect(),
+        }
+    }
+
+    /// The `Info` blocks in `category`, upgraded to CAP v1.2 as necessary.
+    ///
+    /// As with `info_for_language()`, the `Info` blocks of a CAP v1.2 alert are borrowed, while
+    /// only the matching blocks of an older alert are upgraded.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot0_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.0 appendix A.2 */;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    /// let info = alert.info_with_category(oasiscap::v1dot2::Category::Met);
+    /// assert_eq!(info.len(), 1);
+    /// ```
+    pub fn info_with_category(
+        &self,
+        category: v1dot2::Category,
+    ) -> Vec<std::borrow::Cow<'_, v1dot2::Info>> {
+        use std::borrow::Cow;
+        match self {
+            Alert::V1dot0(alert) => alert
+                .info
+                .iter()
+                .filter(|info| {
+                    info.categories
+                        .iter()
+                        .any(|c| v1dot1::Category::from(*c) == category)
+                })
+                .map(|info| Cow::Owned(v1dot2::Info::from(v1dot1::Info::from(info.clone()))))
+                .collect(),
+            Alert::V1dot1(alert) => alert
+                .info
+                .iter()
+                .filter(|info| info.categories.contains(&category))
+                .map(|info| Cow::Owned(v1dot2::Info::from(info.clone())))
+                .collect(),
+            Alert::V1dot2(alert) => alert
+                .info_with_category(category)
+                .into_iter()
+                .map(Cow::Borrowed)
+                .collect(),
+        }
+    }
+
+    /// Resolve a dotted key-path like `info.0.headline` to the string value at that location.
+    ///
+    /// Paths are resolved against the CAP v1.2 representation of this alert, upgrading a copy of
+    /// it as necessary. See [`v1dot2::Alert::get_path`] for details.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot0_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.0 appendix A.1 */;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    /// assert_eq!(
+    ///     alert.get_path("info.0.headline").as_deref(),
+    ///     Some("Homeland Security Sets Code ORANGE"),
+    /// );
+    /// ```
+    pub fn get_path(&self, path: &str) -> Option<String> {
+        match self {
+            Alert::V1dot2(alert) => alert.get_path(path),
+            other => other.clone().into_latest().get_path(path),
+        }
+    }
+
+    /// Read a sequence of alerts from `read`, parsing each one as soon as it is complete.
+    ///
+    /// The stream may contain concatenated CAP documents, each with its own `<?xml …?>`
+    /// declaration, or `<alert>` elements of any CAP version nested inside arbitrary wrapper
+    /// elements. Only one alert is held in memory at a time, so this is suitable for archives which
+    /// are too large to read into a `String`.
+    ///
+    /// An alert which fails to parse is reported as `StreamError::Alert`, and iteration continues
+    /// with the next alert. Malformed XML is reported as `StreamError::Xml`, after which the
+    /// iterator ends.
+    ///
+    /// ```
+    /// # let first = include_str!("../fixtures/v1dot0_appendix_adot1.xml");
+    /// # let second = include_str!("../fixtures/v1dot2_appendix_adot1.xml");
+    /// # let file = format!("{}\n{}", first, second);
+    /// # let file = file.as_bytes();
+    /// // let file = std::fs::File::open("alerts.xml")?;
+    /// for alert in oasiscap::Alert::iter_from_reader(file) {
+    ///     let alert = alert.unwrap();
+    ///     println!("{}", alert.identifier());
+    /// }
+    /// # assert_eq!(oasiscap::Alert::iter_from_reader(file).count(), 2);
+    /// ```
+    pub fn iter_from_reader<R: std::io::Read>(
+        read: R,
+    ) -> impl Iterator<Item = Result<Alert, crate::StreamError>> {
+        crate::stream::Alerts::new(read)
+    }
+
+    /// Parse an alert, handling invalid URLs according to `policy`.
+    ///
+    /// `str::parse()` uses `UrlPolicy::Lenient`, which repairs or discards some invalid URLs in
+    /// `<web>` and `<uri>` elements. `UrlPolicy::Strict` makes those URLs a parse error instead.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.1 */;
+    /// use oasiscap::{Alert, UrlPolicy};
+    /// let input = input.replace("http://www.dhs.gov/dhspublic/display?theme=29", "www.dhs.gov");
+    ///
+    /// let alert = Alert::from_str_with_url_policy(&input, UrlPolicy::Lenient).unwrap();
+    /// assert_eq!(
+    ///     alert.into_latest().info[0].web.as_ref().map(|url| url.as_str()),
+    ///     Some("http://www.dhs.gov/"),
+    /// );
+    ///
+    /// assert!(Alert::from_str_with_url_policy(&input, UrlPolicy::Strict).is_err());
+    /// ```
+    pub fn from_str_with_url_policy(
+        s: &str,
+        policy: crate::UrlPolicy,
+    ) -> Result<Self, crate::Error> {
+        let alert = s.parse()?;
+        if policy == crate::UrlPolicy::Strict {
+            crate::url::check_strict(s)?;
+        }
+        Ok(alert)
+    }
+
+    /// Parse an alert whose polygons and circles list coordinates in `order`.
+    ///
+    /// CAP specifies `latitude,longitude`, which `str::parse()` expects. This allows reading alerts
+    /// from a producer known to emit `longitude,latitude` instead. The parsed points are correct,
+    /// so serializing the alert again produces conforming CAP.
+    ///
+    /// The coordinates in each `<polygon>` and `<circle>` are swapped before parsing. Error
+    /// locations still refer to `s`, but coordinates quoted in error messages appear swapped.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// use oasiscap::{geo::CoordinateOrder, Alert};
+    /// let expected: Alert = input.parse().unwrap();
+    ///
+    /// let swapped = input.replace(
+    ///     "38.47,-120.14 38.34,-119.95 38.52,-119.74 38.62,-119.89 38.47,-120.14",
+    ///     "-120.14,38.47 -119.95,38.34 -119.74,38.52 -119.89,38.62 -120.14,38.47",
+    /// );
+    /// let alert =
+    ///     Alert::from_str_with_coordinate_order(&swapped, CoordinateOrder::LongitudeLatitude)
+    ///         .unwrap();
+    /// assert_eq!(alert, expected);
+    /// ```
+    pub fn from_str_with_coordinate_order(
+        s: &str,
+        order: crate::geo::CoordinateOrder,
+    ) -> Result<Self, crate::Error> {
+        match order {
+            crate::geo::CoordinateOrder::LatitudeLongitude => s.parse(),
+            crate::geo::CoordinateOrder::LongitudeLatitude => {
+                crate::geo::swap_coordinates(s).parse()
+            }
+        }
+    }
+
+    /// Parse every alert in an IPAWS-OPEN response.
+    ///
+    /// IPAWS-OPEN returns alerts inside a SOAP envelope, sometimes with further wrapper elements
+    /// such as `COGProfile`. This finds each `<alert>` element of any CAP version, wherever it
+    /// appears in the document, and ignores everything else. See
+    /// [`iter_from_reader`](Self::iter_from_reader) for the details.
+    ///
+    /// Returns the first error encountered, if any.
+    ///
+    /// ```
+    /// # let alert = include_str!("../fixtures/ipaws-5e6dd964023f1930ef638846.xml");
+    /// # let response = format!(r#"<soapenv:Envelope xmlns:soapenv="http://schemas.xmlsoap.org/soap/envelope/">
+    /// #   <soapenv:Body><ns1:messageResponseTypeDef xmlns:ns1="http://gov.fema.ipaws.services/IPAWS_CAPService/">
+    /// #     {}
+    /// #   </ns1:messageResponseTypeDef></soapenv:Body>
+    /// # </soapenv:Envelope>"#, alert);
+    /// // let response: &str = /* a SOAP response from IPAWS-OPEN */;
+    /// let alerts = oasiscap::Alert::from_ipaws_response(&response).unwrap();
+    /// assert_eq!(alerts.len(), 1);
+    /// ```
+    pub fn from_ipaws_response(xml: &str) -> Result<Vec<Alert>, crate::StreamError> {
+        Self::iter_from_reader(xml.as_bytes()).collect()
+    }
+
+    /// Compare two alerts without regard to the order of entries in their key-value maps.
+    ///
+    /// Alerts of different CAP versions are never equal. Alerts of the same version are compared
+    /// in their CAP v1.2 form; see [`v1dot2::Alert::semantically_eq`] for details.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot1_appendix_adot3.xml");
+    /// // let input: &str = /* CAP v1.1 appendix A.3 */;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    /// assert!(alert.semantically_eq(&alert.clone()));
+    /// assert!(!alert.semantically_eq(&alert.clone().into_latest().into()));
+    /// ```
+    pub fn semantically_eq(&self, other: &Alert) -> bool {
+        match (self, other) {
+            (Alert::V1dot2(a), Alert::V1dot2(b)) => a.semantically_eq(b),
+            (Alert::V1dot1(_), Alert::V1dot1(_)) | (Alert::V1dot0(_), Alert::V1dot0(_)) => self
+                .clone()
+                .into_latest()
+                .semantically_eq(&other.clone().into_latest()),
+            _ => false,
+        }
+    }
+
+    /// Write this alert as XML to `w`.
+    ///
+    /// This produces the same output as `to_string()`, without the `Display` machinery. Note that
+    /// `xml_serde` can only serialize to a `String`, so the document is still assembled in memory
+    /// before being written. The versioned alert types have the same method.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot1_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.1 appendix A.1 */;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    ///
+    /// let mut buf = Vec::new();
+    /// alert.write_xml(&mut buf).unwrap();
+    /// assert_eq!(String::from_utf8(buf).unwrap(), alert.to_string());
+    /// ```
+    pub fn write_xml<W: std::io::Write>(&self, w: W) -> Result<(), crate::Error> {
+        crate::rewrite::write_xml(self, w)
+    }
+
+    /// Append this alert as XML to `buf`.
+    ///
+    /// This produces the same output as `to_string()`, but lets a caller which serializes many
+    /// alerts clear and reuse one buffer rather than keeping a new `String` for each. `xml_serde`
+    /// can only produce a `String` of its own, so each document is still assembled in a temporary
+    /// allocation before being copied into `buf`.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.1 */;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    ///
+    /// let mut buf = String::new();
+    /// for _ in 0..2 {
+    ///     buf.clear();
+    ///     alert.write_to_string(&mut buf).unwrap();
+    ///     assert_eq!(buf, alert.to_string());
+    /// }
+    /// ```
+    pub fn write_to_string(&self, buf: &mut String) -> Result<(), crate::Error> {
+        buf.push_str(&crate::parse_error::to_string(self)?);
+        Ok(())
+    }
+
+    /// Serialize this alert as XML, with each element on its own line and indented by two spaces.
+    ///
+    /// The output parses back into an identical alert, which makes it suitable for logs and fixture
+    /// files.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    ///
+    /// let pretty = alert.to_string_pretty().unwrap();
+    /// assert!(pretty.contains("\n  <cap:info>\n    <cap:category>"));
+    /// assert_eq!(pretty.parse::<oasiscap::Alert>().unwrap(), alert);
+    /// ```
+    pub fn to_string_pretty(&self) -> Result<String, crate::Error> {
+        crate::rewrite::to_string_pretty(self)
+    }
+
+    /// Serialize this alert as XML, according to `options`.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    ///
+    /// let xml = alert.to_string_with(oasiscap::XmlOutputOptions {
+    ///     default_namespace: true,
+    ///     indent: true,
+    /// }).unwrap();
+    /// let root = &xml[xml.find("<alert ").unwrap()..];
+    /// let root = &root[..root.find('>').unwrap()];
+    /// assert!(root.contains(r#"xmlns="urn:oasis:names:tc:emergency:cap:1.2""#));
+    /// assert!(xml.contains("\n  <info>"));
+    /// assert_eq!(xml.parse::<oasiscap::Alert>().unwrap(), alert);
+    /// ```
+    pub fn to_string_with(&self, options: crate::XmlOutputOptions) -> Result<String, crate::Error> {
+        crate::rewrite::to_string_with(self, options)
+    }
 }
 
 impl std::str::FromStr for Alert {
-    type Err = xml_serde::Error;
+    type Err = crate::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        xml_serde::from_str(s)
+        crate::parse_error::from_str(s).map_err(|e| crate::Error::new(s, e))
     }
 }
 
@@ -130,10 +130,147 @@ use std::str::FromStr;
 ///
 /// [dateTime]: https://www.w3.org/TR/xmlschema-2/#dateTime
 /// [Google's Public Alert extended CAP v1.0 schema]: https://github.com/google/cap-library/blob/master/schema/cap10_extended.xsd#L54
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[repr(transparent)]
 pub struct DateTime(chrono::DateTime<FixedOffset>);
 
+impl DateTime {
+    /// The current time in UTC, truncated to whole seconds.
+    pub fn now() -> Self {
+        chrono::Utc::now().into()
+    }
+
+    /// Convert a `chrono::DateTime` to a CAP timestamp expressed in `offset`, truncated to whole
+    /// seconds.
+    ///
+    /// `From<chrono::DateTime<Tz>>` keeps the offset of `dt`. This instead converts the same
+    /// instant into `offset`, which determines how the timestamp is written.
+    ///
+    /// ```
+    /// # use oasiscap::DateTime;
+    /// use chrono::FixedOffset;
+    /// let dt: chrono::DateTime<chrono::Utc> = "2003-06-17T21:57:00.250Z".parse().unwrap();
+    ///
+    /// let pacific = DateTime::from_chrono_in_offset(dt, FixedOffset::west(7 * 3600));
+    /// assert_eq!(pacific.to_string(), "2003-06-17T14:57:00-07:00");
+    /// assert_eq!(pacific, DateTime::from(dt));
+    ///
+    /// let utc = DateTime::from_chrono_in_offset(dt, FixedOffset::east(0));
+    /// assert_eq!(utc.to_string(), "2003-06-17T21:57:00-00:00");
+    /// ```
+    pub fn from_chrono_in_offset<Tz: chrono::TimeZone>(
+        dt: chrono::DateTime<Tz>,
+        offset: FixedOffset,
+    ) -> Self {
+        Self::from(dt.with_timezone(&offset))
+    }
+
+    /// Express the same instant in a different `offset`.
+    ///
+    /// ```
+    /// # use oasiscap::DateTime;
+    /// use chrono::FixedOffset;
+    /// let sent: DateTime = "2003-06-17T14:57:00-07:00".parse().unwrap();
+    ///
+    /// let eastern = sent.with_offset(FixedOffset::west(4 * 3600));
+    /// assert_eq!(eastern.to_string(), "2003-06-17T17:57:00-04:00");
+    /// assert_eq!(eastern, sent);
+    /// assert_eq!(eastern.timestamp(), sent.timestamp());
+    /// ```
+    pub fn with_offset(&self, offset: FixedOffset) -> Self {
+        Self(self.0.with_timezone(&offset))
+    }
+
+    /// Add a `chrono::Duration` to this timestamp, returning `None` on overflow.
+    ///
+    /// Any fractional seconds in `duration` are discarded from the result.
+    ///
+    /// ```
+    /// # use oasiscap::DateTime;
+    /// let sent: DateTime = "2003-06-17T14:57:00-07:00".parse().unwrap();
+    /// let expires = sent.checked_add(chrono::Duration::hours(6)).unwrap();
+    /// assert_eq!(expires.to_string(), "2003-06-17T20:57:00-07:00");
+    /// assert_eq!(expires.timestamp() - sent.timestamp(), 6 * 3600);
+    ///
+    /// // Fractional seconds are discarded
+    /// let expires = sent.checked_add(chrono::Duration::milliseconds(1500)).unwrap();
+    /// assert_eq!(expires.to_string(), "2003-06-17T14:57:01-07:00");
+    /// ```
+    pub fn checked_add(&self, duration: chrono::Duration) -> Option<Self> {
+        self.0.checked_add_signed(duration).map(Self::from)
+    }
+
+    /// The number of seconds since the Unix epoch.
+    pub fn timestamp(&self) -> i64 {
+        self.0.timestamp()
+    }
+
+    /// Format this timestamp like `to_string()`, except that UTC is written as `Z` rather than
+    /// `-00:00`.
+    ///
+    /// CAP v1.1 and v1.2 forbid `Z`, but some systems require it. `DateTime` parses both forms.
+    ///
+    /// ```
+    /// # use oasiscap::DateTime;
+    /// let utc: DateTime = "2002-05-24T16:49:00-00:00".parse().unwrap();
+    /// assert_eq!(utc.to_string_z(), "2002-05-24T16:49:00Z");
+    /// assert_eq!(utc.to_string_z().parse::<DateTime>().unwrap(), utc);
+    ///
+    /// let local: DateTime = "2002-05-24T16:49:00-07:00".parse().unwrap();
+    /// assert_eq!(local.to_string_z(), "2002-05-24T16:49:00-07:00");
+    /// ```
+    pub fn to_string_z(&self) -> String {
+        if self.0.offset().local_minus_utc() == 0 {
+            self.0.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+        } else {
+            self.to_string()
+        }
+    }
+
+    /// Parse a timestamp like `str::parse()`, additionally reporting how its time zone was written.
+    ///
+    /// The `DateTime` is the same regardless of how UTC was written, so this is only useful for
+    /// diagnostics, e.g. to gauge how closely a producer follows CAP v1.2.
+    ///
+    /// ```
+    /// # use oasiscap::{DateTime, TzOrigin};
+    /// let (utc, origin) = DateTime::from_str_with_origin("2002-05-24T16:49:00Z").unwrap();
+    /// assert_eq!(origin, TzOrigin::Zulu);
+    /// assert_eq!(utc, "2002-05-24T16:49:00-00:00".parse::<DateTime>().unwrap());
+    ///
+    /// let origin = |s| DateTime::from_str_with_origin(s).unwrap().1;
+    /// assert_eq!(origin("2002-05-24T16:49:00+00:00"), TzOrigin::PositiveZero);
+    /// assert_eq!(origin("2002-05-24T16:49:00-00:00"), TzOrigin::NegativeZero);
+    /// assert_eq!(origin("2002-05-24T16:49:00-07:00"), TzOrigin::Numeric);
+    /// ```
+    pub fn from_str_with_origin(s: &str) -> Result<(Self, TzOrigin), chrono::ParseError> {
+        let dt = s.parse()?;
+        let origin = if s.ends_with('Z') {
+            TzOrigin::Zulu
+        } else if s.ends_with("+00:00") {
+            TzOrigin::PositiveZero
+        } else if s.ends_with("-00:00") {
+            TzOrigin::NegativeZero
+        } else {
+            TzOrigin::Numeric
+        };
+        Ok((dt, origin))
+    }
+}
+
+/// How the time zone of a timestamp was written, as reported by `DateTime::from_str_with_origin()`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TzOrigin {
+    /// UTC, written as `Z`, which CAP v1.1 and v1.2 forbid
+    Zulu,
+    /// UTC, written as `+00:00`
+    PositiveZero,
+    /// UTC, written as `-00:00`, which is how `DateTime` formats UTC
+    NegativeZero,
+    /// Any other offset from UTC
+    Numeric,
+}
+
 impl FromStr for DateTime {
     type Err = chrono::ParseError;
 
@@ -225,6 +362,14 @@ mod tests {
         assert!(DateTime::from_str("2002-05-24T16:49:00").is_err());
     }
 
+    #[test]
+    fn now() {
+        let now = DateTime::now();
+        assert_eq!(now.0.nanosecond(), 0);
+        assert_eq!(now.0.offset().local_minus_utc(), 0);
+        assert!((chrono::Utc::now().timestamp() - now.timestamp()).abs() <= 1);
+    }
+
     #[test]
     fn parse_utc() {
         // Zero offset is fine according to CAP v1.2
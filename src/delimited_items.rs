@@ -104,6 +104,11 @@ use std::str::FromStr;
 ///     Items::try_from(vec![" foo "]).unwrap().to_string(),
 ///     "\" foo \"",
 /// );
+///
+/// // Any whitespace is quoted, since any whitespace is a delimiter
+/// let items = Items::try_from(vec!["foo\tbar", "baz\nquxx"]).unwrap();
+/// assert_eq!(items.to_string(), "\"foo\tbar\" \"baz\nquxx\"");
+/// assert_eq!(items.to_string().parse::<Items>().unwrap(), items);
 /// ```
 #[derive(Debug, Clone, Eq, PartialEq, Default)]
 pub struct Items(Vec<Item>);
@@ -161,6 +166,45 @@ impl Items {
     pub fn into_inner(self) -> Vec<Item> {
         self.0
     }
+
+    /// Instantiate `Items` from an iterator of strings, validating each one as an `Item`.
+    ///
+    /// This is the iterator counterpart to `Items::try_from(Vec<S>)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oasiscap::delimited_items::*;
+    ///
+    /// let names = ["foo", "bar baz"].iter().map(|s| s.to_string());
+    /// let items = Items::try_from_iter(names).unwrap();
+    /// assert_eq!(items.to_string(), "foo \"bar baz\"");
+    ///
+    /// assert_eq!(Items::try_from_iter(["foo", "bar\"baz"]), Err(InvalidItemError));
+    /// ```
+    pub fn try_from_iter<I, S>(iter: I) -> Result<Self, InvalidItemError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        iter.into_iter().map(|s| Item::try_from(s.into())).collect()
+    }
+
+    /// Append an `Item`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oasiscap::delimited_items::*;
+    ///
+    /// let mut items = Items::default();
+    /// items.push(Item::try_from("foo").unwrap());
+    /// items.push(Item::try_from("bar baz").unwrap());
+    /// assert_eq!(items.to_string(), "foo \"bar baz\"");
+    /// ```
+    pub fn push(&mut self, item: Item) {
+        self.0.push(item);
+    }
 }
 
 impl Deref for Items {
@@ -246,7 +290,7 @@ impl std::fmt::Display for Items {
         for (index, item) in self.iter().enumerate() {
             let space = if index == 0 { "" } else { " " };
 
-            if item.contains(' ') {
+            if item.contains(|c: char| c.is_ascii_whitespace()) {
                 write!(f, "{}\"{}\"", space, item)?;
             } else {
                 write!(f, "{}{}", space, item)?;
@@ -260,11 +304,25 @@ impl<S: Into<String>> TryFrom<Vec<S>> for Items {
     type Error = InvalidItemError;
 
     fn try_from(value: Vec<S>) -> Result<Self, Self::Error> {
-        value
-            .into_iter()
-            .map(|s| s.into())
-            .map(Item::try_from)
-            .collect()
+        Self::try_from_iter(value)
+    }
+}
+
+/// Parse `Items` from a slice of strings, as for `TryFrom<Vec<S>>`.
+///
+/// ```
+/// use oasiscap::delimited_items::*;
+///
+/// let items = Items::try_from(&["foo", "bar baz"][..]).unwrap();
+/// assert_eq!(items.to_string(), "foo \"bar baz\"");
+///
+/// assert_eq!(Items::try_from(&["foo", "bar\"baz"][..]), Err(InvalidItemError));
+/// ```
+impl TryFrom<&[&str]> for Items {
+    type Error = InvalidItemError;
+
+    fn try_from(value: &[&str]) -> Result<Self, Self::Error> {
+        Self::try_from_iter(value.iter().copied())
     }
 }
 
@@ -38,37 +38,30 @@ use std::str::FromStr;
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Sha1([u8; 20]);
 
-impl AsRef<[u8; 20]> for Sha1 {
-    fn as_ref(&self) -> &[u8; 20] {
-        &self.0
-    }
-}
+impl Sha1 {
+    /// Computes the SHA-1 digest of `bytes`.
+    ///
+    /// This is useful for checking content retrieved from a resource's `uri` against its `digest`.
+    /// Requires the `sha1` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "sha1")] {
+    /// use oasiscap::digest::Sha1;
+    /// assert_eq!(
+    ///     Sha1::compute(b"abc"),
+    ///     "a9993e364706816aba3e25717850c26c9cd0d89d".parse::<Sha1>().unwrap(),
+    /// );
+    /// # }
+    /// ```
+    #[cfg(feature = "sha1")]
+    pub fn compute(bytes: &[u8]) -> Self {
+        use ::sha1::Digest;
 
-impl AsRef<[u8]> for Sha1 {
-    fn as_ref(&self) -> &[u8] {
-        self.0.as_slice()
-    }
-}
-
-impl PartialEq<&[u8]> for Sha1 {
-    fn eq(&self, other: &&[u8]) -> bool {
-        self.0.as_slice() == *other
-    }
-}
-impl PartialEq<Sha1> for &[u8] {
-    fn eq(&self, other: &Sha1) -> bool {
-        *self == other.0
-    }
-}
-
-impl PartialEq<[u8; 20]> for Sha1 {
-    fn eq(&self, other: &[u8; 20]) -> bool {
-        &self.0 == other
-    }
-}
-impl PartialEq<Sha1> for [u8; 20] {
-    fn eq(&self, other: &Sha1) -> bool {
-        self == &other.0
+        let mut digest = [0u8; 20];
+        digest.copy_from_slice(&::sha1::Sha1::digest(bytes));
+        Self(digest)
     }
 }
 
@@ -79,84 +72,191 @@ pub enum Sha1ParseError {
     #[error("SHA-1 digest must be 40 characters long: got {0}")]
     Length(usize),
     /// SHA-1 digest must be hexadecimal
-    #[error("SHA-1 digest must hexadecimal: got {0}")]
+    #[error("SHA-1 digest must be hexadecimal: got {0}")]
     Digits(String),
 }
 
-impl FromStr for Sha1 {
-    type Err = Sha1ParseError;
+/// A SHA-256 digest.
+///
+/// CAP specifies SHA-1 for `<digest>`, so this type does not appear in any CAP structure. It is
+/// provided for profiles which convey SHA-256 digests in parameters or out of band, and mirrors the
+/// `Sha1` API.
+///
+/// # Example
+///
+/// ```
+/// use oasiscap::digest::Sha256;
+///
+/// let bytes: [u8; 32] = [
+///         0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae,
+///         0x22, 0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61,
+///         0xf2, 0x00, 0x15, 0xad,
+///     ];
+///
+/// // digest::Sha256 parses from a hex string
+/// let digest = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+///     .parse::<Sha256>()
+///     .unwrap();
+/// assert_eq!(digest, Sha256::from(bytes));
+///
+/// // digest::Sha256 converts to a byte array
+/// assert_eq!(<[u8; 32]>::from(digest), bytes);
+///
+/// // digest::Sha256 compares against byte arrays and byte slices
+/// assert_eq!(digest, bytes);
+/// assert_eq!(bytes, digest);
+/// assert_eq!(digest, bytes.as_slice());
+/// assert_eq!(bytes.as_slice(), digest);
+///
+/// // digest::Sha256 displays as a hex string
+/// assert_eq!(
+///     digest.to_string(),
+///     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+/// );
+///
+/// // digest::Sha256 is case-insensitive
+/// let upper_digest = "BA7816BF8F01CFEA414140DE5DAE2223B00361A396177A9CB410FF61F20015AD"
+///     .parse::<Sha256>()
+///     .unwrap();
+/// assert_eq!(upper_digest, digest);
+///
+/// // digest::Sha256 rejects anything but hex digits
+/// assert!("+a7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+///     .parse::<Sha256>()
+///     .is_err());
+/// assert!("é7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+///     .parse::<Sha256>()
+///     .is_err());
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Sha256([u8; 32]);
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim();
-        if s.len() != 40 {
-            return Err(Sha1ParseError::Length(s.len()));
-        }
+/// The error returned when a `Sha256` would be invalid.
+#[derive(thiserror::Error, Debug)]
+pub enum Sha256ParseError {
+    /// SHA-256 digest must be 64 characters long
+    #[error("SHA-256 digest must be 64 characters long: got {0}")]
+    Length(usize),
+    /// SHA-256 digest must be hexadecimal
+    #[error("SHA-256 digest must be hexadecimal: got {0}")]
+    Digits(String),
+}
 
-        let mut bytes = [0u8; 20];
-        for octet in 0..20 {
-            bytes[octet] = u8::from_str_radix(&s[octet * 2..octet * 2 + 2], 16)
-                .map_err(|_| Sha1ParseError::Digits(s.into()))?;
+/// Decodes `s` as `N` octets of hexadecimal, returning `None` if `s` is anything else.
+///
+/// Every byte is checked before decoding, so multibyte characters and signs are rejected rather
+/// than being sliced or passed to `u8::from_str_radix`.
+fn parse_hex<const N: usize>(s: &str) -> Option<[u8; N]> {
+    fn nibble(digit: u8) -> u8 {
+        match digit {
+            b'0'..=b'9' => digit - b'0',
+            b'a'..=b'f' => digit - b'a' + 10,
+            _ => digit - b'A' + 10,
         }
-
-        Ok(Self(bytes))
     }
-}
 
-impl std::fmt::Display for Sha1 {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f,
-               "{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
-               self.0[0],
-               self.0[1],
-               self.0[2],
-               self.0[3],
-               self.0[4],
-               self.0[5],
-               self.0[6],
-               self.0[7],
-               self.0[8],
-               self.0[9],
-               self.0[10],
-               self.0[11],
-               self.0[12],
-               self.0[13],
-               self.0[14],
-               self.0[15],
-               self.0[16],
-               self.0[17],
-               self.0[18],
-               self.0[19],
-        )
+    let s = s.as_bytes();
+    if s.len() != N * 2 || !s.iter().all(u8::is_ascii_hexdigit) {
+        return None;
     }
-}
 
-impl From<[u8; 20]> for Sha1 {
-    fn from(v: [u8; 20]) -> Self {
-        Self(v)
+    let mut bytes = [0u8; N];
+    for (octet, pair) in bytes.iter_mut().zip(s.chunks_exact(2)) {
+        *octet = nibble(pair[0]) << 4 | nibble(pair[1]);
     }
+    Some(bytes)
 }
 
-impl From<Sha1> for [u8; 20] {
-    fn from(v: Sha1) -> Self {
-        v.0
-    }
-}
+macro_rules! impl_digest {
+    ( $t:ident, $len:literal, $err:ident ) => {
+        impl AsRef<[u8; $len]> for $t {
+            fn as_ref(&self) -> &[u8; $len] {
+                &self.0
+            }
+        }
 
-impl Serialize for Sha1 {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_str(&self.to_string())
-    }
-}
+        impl AsRef<[u8]> for $t {
+            fn as_ref(&self) -> &[u8] {
+                self.0.as_slice()
+            }
+        }
 
-impl<'de> Deserialize<'de> for Sha1 {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let str = <std::borrow::Cow<str>>::deserialize(deserializer)?;
-        str.parse().map_err(D::Error::custom)
-    }
+        impl PartialEq<&[u8]> for $t {
+            fn eq(&self, other: &&[u8]) -> bool {
+                self.0.as_slice() == *other
+            }
+        }
+        impl PartialEq<$t> for &[u8] {
+            fn eq(&self, other: &$t) -> bool {
+                *self == other.0
+            }
+        }
+
+        impl PartialEq<[u8; $len]> for $t {
+            fn eq(&self, other: &[u8; $len]) -> bool {
+                &self.0 == other
+            }
+        }
+        impl PartialEq<$t> for [u8; $len] {
+            fn eq(&self, other: &$t) -> bool {
+                self == &other.0
+            }
+        }
+
+        impl FromStr for $t {
+            type Err = $err;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let s = s.trim();
+                if s.len() != $len * 2 {
+                    return Err($err::Length(s.len()));
+                }
+
+                parse_hex(s).map(Self).ok_or_else(|| $err::Digits(s.into()))
+            }
+        }
+
+        impl std::fmt::Display for $t {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                for octet in self.0 {
+                    write!(f, "{:02x}", octet)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl From<[u8; $len]> for $t {
+            fn from(v: [u8; $len]) -> Self {
+                Self(v)
+            }
+        }
+
+        impl From<$t> for [u8; $len] {
+            fn from(v: $t) -> Self {
+                v.0
+            }
+        }
+
+        impl Serialize for $t {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_str(&self.to_string())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let str = <std::borrow::Cow<str>>::deserialize(deserializer)?;
+                str.parse().map_err(D::Error::custom)
+            }
+        }
+    };
 }
+
+impl_digest!(Sha1, 20, Sha1ParseError);
+impl_digest!(Sha256, 32, Sha256ParseError);
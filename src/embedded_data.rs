@@ -5,14 +5,82 @@ use std::fmt::Debug;
 use std::ops::Deref;
 
 /// Binary data embedded inside a CAP message.
+///
+/// `EmbeddedContent` stores the decoded bytes. It is base64-encoded when serialized and decoded when
+/// deserialized, so callers never need to handle the encoding or its line wrapping.
+///
+/// # Example
+///
+/// ```
+/// let content = oasiscap::EmbeddedContent::from_bytes(b"abc");
+/// assert_eq!(content.as_slice(), b"abc");
+/// assert_eq!(content.to_string(), "YWJj");
+/// ```
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct EmbeddedContent(Vec<u8>);
 
 impl EmbeddedContent {
+    /// Embeds a copy of `bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self(bytes.to_vec())
+    }
+
+    /// Embeds everything read from `reader`, e.g. a file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let content = oasiscap::EmbeddedContent::from_reader(&b"abc"[..]).unwrap();
+    /// assert_eq!(content.as_slice(), b"abc");
+    /// ```
+    pub fn from_reader<R: std::io::Read>(mut reader: R) -> std::io::Result<Self> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Ok(Self(bytes))
+    }
+
     /// Returns a byte slice of the embedded data.
     pub fn as_slice(&self) -> &[u8] {
         self.0.as_slice()
     }
+
+    /// Returns the length of the decoded embedded data, in bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let content: oasiscap::EmbeddedContent = String::from("YWJj").try_into().unwrap();
+    /// assert_eq!(content.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there is no embedded data.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Computes the SHA-1 digest of the embedded data.
+    ///
+    /// The digest is computed over the decoded bytes rather than over their base64 encoding, which
+    /// is what CAP requires for `<digest>`. Requires the `sha1` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "sha1")] {
+    /// let content = oasiscap::EmbeddedContent::from(b"abc".to_vec());
+    /// assert_eq!(
+    ///     content.sha1().to_string(),
+    ///     "a9993e364706816aba3e25717850c26c9cd0d89d",
+    /// );
+    /// # }
+    /// ```
+    #[cfg(feature = "sha1")]
+    pub fn sha1(&self) -> crate::digest::Sha1 {
+        crate::digest::Sha1::compute(self.0.as_slice())
+    }
 }
 
 impl Deref for EmbeddedContent {
@@ -0,0 +1,378 @@
+//! Types for CAP alerts distributed through Atom and RSS feeds.
+//!
+//! Public alerting systems commonly publish an Atom feed in which each entry either embeds a CAP
+//! alert in its `<content>` or links to one. Embedded alerts may appear as a child element, or as
+//! escaped XML text:
+//!
+//! ```xml
+//! <content type="text/xml"><alert xmlns="urn:oasis:names:tc:emergency:cap:1.2">…</alert></content>
+//! <content type="text">&lt;alert xmlns="urn:oasis:names:tc:emergency:cap:1.2"&gt;…</content>
+//! ```
+//!
+//! Both forms are supported, as are RSS 2.0 `<item>`s carrying `<link>` or `<enclosure>` elements.
+//! Content which is not a CAP `<alert>`, such as an HTML summary, is ignored.
+//!
+//! Requires the `feed` feature.
+//!
+//! # Example
+//!
+//! ```
+//! # let input = include_str!("../fixtures/feeds/atom.xml");
+//! // let input: &str = /* an Atom feed */;
+//! let feed = oasiscap::feed::parse(input).unwrap();
+//!
+//! for alert in feed.alerts() {
+//!     let alert = alert.unwrap();
+//!     println!("embedded alert: {}", alert.identifier());
+//! }
+//!
+//! for href in feed.links() {
+//!     println!("linked alert: {}", href);
+//! }
+//! # assert_eq!(feed.alerts().count(), 2);
+//! # assert_eq!(feed.links().collect::<Vec<_>>(), vec!["https://alerts.example.com/cap/3.xml"]);
+//! ```
+
+use crate::subtree;
+use xml::reader::{EventReader, XmlEvent};
+
+const ATOM_NAMESPACE: &str = "http://www.w3.org/2005/Atom";
+const CAP_MIME_TYPE: &str = "application/cap+xml";
+
+/// Parse an Atom or RSS feed.
+pub fn parse(xml: &str) -> Result<Feed, FeedError> {
+    let mut reader = EventReader::new(xml.as_bytes());
+
+    let kind = loop {
+        match reader.next()? {
+            XmlEvent::StartElement { name, .. } => {
+                match (name.local_name.as_str(), name.namespace.as_deref()) {
+                    ("feed", Some(ATOM_NAMESPACE)) => break Kind::Atom,
+                    ("rss", None) => break Kind::Rss,
+                    _ => return Err(FeedError::NotAFeed(name.local_name)),
+                }
+            }
+            XmlEvent::EndDocument => return Err(FeedError::NotAFeed(String::new())),
+            _ => {}
+        }
+    };
+
+    let mut entries = Vec::new();
+    loop {
+        match reader.next()? {
+            XmlEvent::StartElement { name, .. } => {
+                match (kind, name.local_name.as_str(), name.namespace.as_deref()) {
+                    (Kind::Atom, "entry", Some(ATOM_NAMESPACE)) | (Kind::Rss, "item", None) => {
+                        entries.push(Entry::read(&mut reader, kind)?)
+                    }
+                    // RSS nests items inside a `<channel>`, so descend into everything else
+                    _ => {}
+                }
+            }
+            XmlEvent::EndDocument => break,
+            _ => {}
+        }
+    }
+
+    Ok(Feed { entries })
+}
+
+/// An Atom or RSS feed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Feed {
+    /// The Atom `<entry>` or RSS `<item>` elements in this feed, in document order
+    pub entries: Vec<Entry>,
+}
+
+impl Feed {
+    /// Iterate over the alerts embedded in this feed, parsing each one.
+    pub fn alerts(&self) -> impl Iterator<Item = Result<crate::Alert, crate::Error>> + '_ {
+        self.entries
+            .iter()
+            .filter_map(|entry| entry.content.as_deref())
+            .map(str::parse)
+    }
+
+    /// Iterate over the URLs of alerts linked from, but not embedded in, this feed.
+    ///
+    /// For each entry without embedded content, this yields the links which specify the
+    /// `application/cap+xml` media type. If an entry has no such links, its links without a media
+    /// type are yielded instead.
+    pub fn links(&self) -> impl Iterator<Item = &str> + '_ {
+        self.entries
+            .iter()
+            .filter(|entry| entry.content.is_none())
+            .flat_map(|entry| {
+                let is_cap = |link: &&Link| {
+                    link.mime_type
+                        .as_deref()
+                        .is_some_and(|mime_type| mime_type.eq_ignore_ascii_case(CAP_MIME_TYPE))
+                };
+                let has_cap = entry.links.iter().any(|link| is_cap(&link));
+                entry
+                    .links
+                    .iter()
+                    .filter(move |link| {
+                        if has_cap {
+                            is_cap(link)
+                        } else {
+                            link.mime_type.is_none()
+                        }
+                    })
+                    .map(|link| link.href.as_str())
+            })
+    }
+}
+
+/// An Atom `<entry>` or RSS `<item>`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Entry {
+    /// The entry's `<id>` or `<guid>`
+    pub id: Option<String>,
+    /// The entry's `<title>`
+    pub title: Option<String>,
+    /// The CAP alert embedded in the entry's `<content>` as an XML string, whether it was a child
+    /// element or text
+    pub content: Option<String>,
+    /// The entry's links
+    pub links: Vec<Link>,
+}
+
+/// A link from a feed entry.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Link {
+    /// The linked URL
+    pub href: String,
+    /// The media type of the linked resource, if specified
+    pub mime_type: Option<String>,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Kind {
+    Atom,
+    Rss,
+}
+
+impl Entry {
+    fn read<R: std::io::Read>(reader: &mut EventReader<R>, kind: Kind) -> Result<Self, FeedError> {
+        let mut entry = Entry {
+            id: None,
+            title: None,
+            content: None,
+            links: Vec::new(),
+        };
+
+        loop {
+            let (name, attributes) = match reader.next()? {
+                XmlEvent::StartElement {
+                    name, attributes, ..
+                } => (name, attributes),
+                XmlEvent::EndElement { .. } => return Ok(entry),
+                _ => continue,
+            };
+            let attribute = |local_name: &str| {
+                attributes
+                    .iter()
+                    .find(|attr| {
+                        attr.name.local_name == local_name && attr.name.namespace.is_none()
+                    })
+                    .map(|attr| attr.value.clone())
+            };
+
+            match (kind, name.local_name.as_str(), name.namespace.as_deref()) {
+                (Kind::Atom, "id", Some(ATOM_NAMESPACE)) | (Kind::Rss, "guid", None) => {
+                    entry.id = Some(subtree::read_text(reader)?.trim().into());
+                }
+                (Kind::Atom, "title", Some(ATOM_NAMESPACE)) | (Kind::Rss, "title", None) => {
+                    entry.title = Some(subtree::read_text(reader)?.trim().into());
+                }
+                (Kind::Atom, "link", Some(ATOM_NAMESPACE)) => {
+                    if let Some(href) = attribute("href") {
+                        entry.links.push(Link {
+                            href,
+                            mime_type: attribute("type"),
+                        });
+                    }
+                    subtree::skip_element(reader)?;
+                }
+                (Kind::Atom, "content", Some(ATOM_NAMESPACE)) => {
+                    if let Some(href) = attribute("src") {
+                        // Out-of-line content is a link by another name
+                        entry.links.push(Link {
+                            href,
+                            mime_type: attribute("type"),
+                        });
+                        subtree::skip_element(reader)?;
+                    } else {
+                        entry.content = read_content(reader, attribute("type").as_deref())?;
+                    }
+                }
+                (Kind::Rss, "link", None) => {
+                    let href = subtree::read_text(reader)?.trim().to_string();
+                    if !href.is_empty() {
+                        entry.links.push(Link {
+                            href,
+                            mime_type: None,
+                        });
+                    }
+                }
+                (Kind::Rss, "enclosure", None) => {
+                    if let Some(href) = attribute("url") {
+                        entry.links.push(Link {
+                            href,
+                            mime_type: attribute("type"),
+                        });
+                    }
+                    subtree::skip_element(reader)?;
+                }
+                _ => subtree::skip_element(reader)?,
+            }
+        }
+    }
+}
+
+/// Read the body of a `<content>` element of the given `type`, returning it if it's a CAP alert.
+///
+/// The alert may be a child element, or escaped text.
+fn read_content<R: std::io::Read>(
+    reader: &mut EventReader<R>,
+    content_type: Option<&str>,
+) -> Result<Option<String>, FeedError> {
+    let mut text = String::new();
+    let mut element = None;
+    loop {
+        match reader.next()? {
+            XmlEvent::StartElement {
+                name,
+                attributes,
+                namespace,
+            } => {
+                if element.is_none() && subtree::is_alert(&name) {
+                    let start = XmlEvent::StartElement {
+                        name,
+                        attributes,
+                        namespace,
+                    };
+                    element = Some(subtree::read_element(reader, start)?);
+                } else {
+                    subtree::skip_element(reader)?;
+                }
+            }
+            XmlEvent::Characters(s) | XmlEvent::CData(s) => text.push_str(&s),
+            XmlEvent::EndElement { .. } => break,
+            _ => {}
+        }
+    }
+
+    // HTML content may mention an alert, but never is one
+    let is_html = matches!(
+        content_type,
+        Some(content_type)
+            if content_type.eq_ignore_ascii_case("html") || content_type.eq_ignore_ascii_case("xhtml")
+    );
+    if element.is_some() || is_html {
+        return Ok(element);
+    }
+
+    let text = text.trim();
+    Ok(if root_is_alert(text) {
+        Some(text.to_string())
+    } else {
+        None
+    })
+}
+
+/// Whether the root element of `xml` is a CAP `<alert>`.
+fn root_is_alert(xml: &str) -> bool {
+    for event in EventReader::new(xml.as_bytes()) {
+        match event {
+            Ok(XmlEvent::StartElement { name, .. }) => return subtree::is_alert(&name),
+            Ok(_) => {}
+            Err(_) => return false,
+        }
+    }
+    false
+}
+
+/// The error returned when a feed cannot be parsed.
+#[derive(thiserror::Error, Debug)]
+pub enum FeedError {
+    /// The document is not well-formed XML
+    #[error("invalid XML: {0}")]
+    Xml(#[from] xml::reader::Error),
+    /// The document's root element is neither an Atom `<feed>` nor an RSS `<rss>`
+    #[error("not an Atom or RSS feed: root element is {0:?}")]
+    NotAFeed(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn atom() {
+        let feed = parse(include_str!("../fixtures/feeds/atom.xml")).unwrap();
+        assert_eq!(feed.entries.len(), 3);
+        assert_eq!(
+            feed.entries[0].title.as_deref(),
+            Some("EQ 3.4 Imperial County CA")
+        );
+
+        // One alert is a child element, the other is escaped text
+        let alerts = feed.alerts().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(alerts.len(), 2);
+        assert_eq!(alerts[0].identifier().as_str(), "TRI13970876.1");
+        assert_eq!(alerts[1].identifier().as_str(), "TRI13970876.2");
+
+        // Links to embedded alerts are skipped, as are links to other media types
+        assert_eq!(
+            feed.links().collect::<Vec<_>>(),
+            vec!["https://alerts.example.com/cap/3.xml"]
+        );
+    }
+
+    #[test]
+    fn rss() {
+        let feed = parse(include_str!("../fixtures/feeds/rss.xml")).unwrap();
+        assert_eq!(feed.entries.len(), 2);
+        assert_eq!(feed.alerts().count(), 0);
+        assert_eq!(
+            feed.links().collect::<Vec<_>>(),
+            vec![
+                "https://alerts.example.com/cap/1.xml",
+                "https://alerts.example.com/cap/2.xml",
+            ]
+        );
+    }
+
+    #[test]
+    fn other_content() {
+        let feed = parse(concat!(
+            r#"<feed xmlns="http://www.w3.org/2005/Atom">"#,
+            r#"<entry><content type="html">&lt;alert&gt;Tornado&lt;/alert&gt;</content>"#,
+            r#"<link href="https://alerts.example.com/cap/1.xml" type="application/cap+xml"/>"#,
+            r#"</entry>"#,
+            r#"<entry><content type="xhtml"><div xmlns="http://www.w3.org/1999/xhtml">"#,
+            r#"<alert xmlns="urn:oasis:names:tc:emergency:cap:1.2"/></div></content></entry>"#,
+            r#"<entry><content type="text/xml"><alert xmlns="urn:example">Tornado</alert>"#,
+            r#"</content></entry>"#,
+            r#"</feed>"#,
+        ))
+        .unwrap();
+        assert_eq!(feed.entries.len(), 3);
+        assert!(feed.entries.iter().all(|entry| entry.content.is_none()));
+        assert_eq!(feed.alerts().count(), 0);
+
+        // An entry whose content isn't an alert may still link to one
+        assert_eq!(
+            feed.links().collect::<Vec<_>>(),
+            vec!["https://alerts.example.com/cap/1.xml"]
+        );
+    }
+
+    #[test]
+    fn not_a_feed() {
+        let alert = include_str!("../fixtures/v1dot2_appendix_adot1.xml");
+        assert!(matches!(parse(alert), Err(FeedError::NotAFeed(name)) if name == "alert"));
+    }
+}
@@ -2,6 +2,8 @@
 use serde::de::Error;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::str::FromStr;
+use xml::common::Position;
+use xml::reader::{EventReader, XmlEvent};
 
 /// A geographic point, in WGS 84 (EPSG:4326) coordinates.
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -37,6 +39,177 @@ impl Point {
     pub fn longitude(&self) -> f64 {
         self.longitude
     }
+
+    /// Returns this point with its latitude and longitude rounded to `decimals` decimal places.
+    ///
+    /// ```
+    /// # use oasiscap::geo::Point;
+    /// let point = Point::new(38.470000000001, -120.139999999999).unwrap();
+    /// assert_eq!(point.round(2), Point::new(38.47, -120.14).unwrap());
+    /// assert_eq!(point.round(0), Point::new(38.0, -120.0).unwrap());
+    /// ```
+    pub fn round(&self, decimals: u8) -> Point {
+        let scale = 10f64.powi(decimals.into());
+        let round = |value: f64| {
+            let rounded = (value * scale).round() / scale;
+            // Very large scales overflow to infinity, and produce NaN
+            if rounded.is_finite() {
+                rounded
+            } else {
+                value
+            }
+        };
+        // Rounding cannot move an in-range coordinate out of range
+        Self {
+            latitude: round(self.latitude),
+            longitude: round(self.longitude),
+        }
+    }
+
+    /// Parse one `latitude,longitude` pair from the start of `s`, returning the point and the
+    /// remainder of `s`.
+    ///
+    /// Leading whitespace is skipped, and the pair ends at the next whitespace or at the end of
+    /// `s`. This allows scanning a CAP polygon string point by point without allocating.
+    ///
+    /// ```
+    /// # use oasiscap::geo::Point;
+    /// let (point, rest) = Point::parse_pair(" 38.47,-120.14 38.34,-119.95").unwrap();
+    /// assert_eq!(point, Point::new(38.47, -120.14).unwrap());
+    /// assert_eq!(rest, " 38.34,-119.95");
+    ///
+    /// let (point, rest) = Point::parse_pair(rest).unwrap();
+    /// assert_eq!(point, Point::new(38.34, -119.95).unwrap());
+    /// assert_eq!(rest, "");
+    ///
+    /// assert!(Point::parse_pair("").is_err());
+    /// assert!(Point::parse_pair("38.47 -120.14").is_err());
+    /// assert!(Point::parse_pair("98.47,-120.14").is_err());
+    /// ```
+    pub fn parse_pair(s: &str) -> Result<(Point, &str), InvalidPointError> {
+        Self::parse_pair_with_order(s, CoordinateOrder::LatitudeLongitude)
+    }
+
+    /// Parse one coordinate pair in `order` from the start of `s`, as for `parse_pair()`.
+    ///
+    /// ```
+    /// # use oasiscap::geo::{CoordinateOrder, Point};
+    /// let (point, rest) =
+    ///     Point::parse_pair_with_order("-120.14,38.47 -119.95,38.34", CoordinateOrder::LongitudeLatitude)
+    ///         .unwrap();
+    /// assert_eq!(point, Point::new(38.47, -120.14).unwrap());
+    /// assert_eq!(rest, " -119.95,38.34");
+    /// ```
+    pub fn parse_pair_with_order(
+        s: &str,
+        order: CoordinateOrder,
+    ) -> Result<(Point, &str), InvalidPointError> {
+        let s = s.trim_start();
+        let end = s.find(char::is_whitespace).unwrap_or(s.len());
+        let (pair, rest) = s.split_at(end);
+        Ok((Self::from_str_with_order(pair, order)?, rest))
+    }
+}
+
+// `Point`s are never NaN, since `Point::new()` rejects coordinates which are not in range. Their
+// `PartialEq` is therefore a total equivalence relation, and `partial_cmp()` always succeeds.
+impl Eq for Point {}
+
+/// Points hash by the bit patterns of their coordinates, after normalizing `-0.0` to `0.0` to
+/// match `PartialEq`.
+impl std::hash::Hash for Point {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl Point {
+    /// The bit patterns of the latitude and longitude, after normalizing `-0.0` to `0.0`.
+    fn key(&self) -> (u64, u64) {
+        // Adding zero turns -0.0 into 0.0 and leaves everything else alone
+        (
+            (self.latitude + 0.0).to_bits(),
+            (self.longitude + 0.0).to_bits(),
+        )
+    }
+}
+
+/// Points are ordered by latitude, then by longitude.
+///
+/// This ordering has no geographic significance, but it is deterministic, which allows `Point`s
+/// to be sorted and used as keys in a `BTreeMap`.
+///
+/// ```
+/// # use oasiscap::geo::Point;
+/// let mut points = vec![
+///     Point::new(38.47, -120.14).unwrap(),
+///     Point::new(38.34, -119.95).unwrap(),
+///     Point::new(38.47, -121.0).unwrap(),
+/// ];
+/// points.sort();
+/// assert_eq!(
+///     points,
+///     vec![
+///         Point::new(38.34, -119.95).unwrap(),
+///         Point::new(38.47, -121.0).unwrap(),
+///         Point::new(38.47, -120.14).unwrap(),
+///     ]
+/// );
+///
+/// let distinct = points.iter().chain(&points).collect::<std::collections::HashSet<_>>();
+/// assert_eq!(distinct.len(), 3);
+/// ```
+impl Ord for Point {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let cmp = |a: f64, b: f64| a.partial_cmp(&b).expect("Point coordinates are never NaN");
+        cmp(self.latitude, other.latitude).then_with(|| cmp(self.longitude, other.longitude))
+    }
+}
+
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The mean radius of the Earth, in kilometers.
+pub(crate) const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// The most segments `Polygon::densify()` will split an edge into.
+const MAX_DENSIFY_SEGMENTS: f64 = 10_000.0;
+
+impl Point {
+    /// Convert to a unit vector from the center of a spherical Earth.
+    fn to_vector(self) -> [f64; 3] {
+        let (latitude, longitude) = (self.latitude.to_radians(), self.longitude.to_radians());
+        [
+            latitude.cos() * longitude.cos(),
+            latitude.cos() * longitude.sin(),
+            latitude.sin(),
+        ]
+    }
+
+    /// Convert from a vector from the center of a spherical Earth.
+    fn from_vector([x, y, z]: [f64; 3]) -> Self {
+        let latitude = z.atan2((x * x + y * y).sqrt()).to_degrees();
+        let longitude = y.atan2(x).to_degrees();
+        Self {
+            latitude: latitude.clamp(-90.0, 90.0),
+            longitude: longitude.clamp(-180.0, 180.0),
+        }
+    }
+}
+
+/// The angle between two unit vectors, in radians.
+fn angle_between(a: [f64; 3], b: [f64; 3]) -> f64 {
+    let cross = [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ];
+    let sin = (cross[0] * cross[0] + cross[1] * cross[1] + cross[2] * cross[2]).sqrt();
+    let cos = a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    sin.atan2(cos)
 }
 
 impl std::fmt::Display for Point {
@@ -70,20 +243,116 @@ pub enum InvalidPointError {
     },
 }
 
-impl FromStr for Point {
-    type Err = InvalidPointError;
+/// The order of the coordinates in a point string.
+///
+/// CAP specifies `latitude,longitude`, but some non-conforming producers emit
+/// `longitude,latitude`. Alerts from such a source can be read using
+/// [`Alert::from_str_with_coordinate_order`](crate::Alert::from_str_with_coordinate_order).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum CoordinateOrder {
+    /// `latitude,longitude`, as CAP specifies
+    #[default]
+    LatitudeLongitude,
+    /// `longitude,latitude`
+    LongitudeLatitude,
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+/// Swap the coordinates of every point in the `<polygon>` and `<circle>` elements of `xml`, an
+/// alert listing them `longitude,latitude`, so that it lists them `latitude,longitude` instead.
+///
+/// Each pair keeps its length, so offsets into the result are also offsets into `xml`.
+pub(crate) fn swap_coordinates(xml: &str) -> String {
+    let mut swapped = String::with_capacity(xml.len());
+    let mut copied = 0;
+
+    let mut reader = EventReader::new(xml.as_bytes());
+    let mut in_shape = false;
+    loop {
+        match reader.next() {
+            Ok(XmlEvent::StartElement { name, .. }) => {
+                in_shape = matches!(name.local_name.as_str(), "polygon" | "circle")
+                    && crate::subtree::is_cap(&name);
+            }
+            Ok(XmlEvent::EndElement { .. }) => in_shape = false,
+            Ok(XmlEvent::Characters(_)) if in_shape => {
+                let position = reader.position();
+                let start = crate::parse_error::byte_offset(xml, position.row, position.column);
+                let end = xml[start..].find('<').map_or(xml.len(), |end| start + end);
+                swapped.push_str(&xml[copied..start]);
+                swap_pairs(&xml[start..end], &mut swapped);
+                copied = end;
+            }
+            // Leave the rest for the parser to report
+            Ok(XmlEvent::EndDocument) | Err(_) => break,
+            Ok(_) => {}
+        }
+    }
+
+    swapped.push_str(&xml[copied..]);
+    swapped
+}
+
+/// Append `text` to `out`, swapping the values on either side of the comma in each
+/// whitespace-separated pair.
+fn swap_pairs(text: &str, out: &mut String) {
+    let mut rest = text;
+    while !rest.is_empty() {
+        let start = rest
+            .find(|c: char| !c.is_whitespace())
+            .unwrap_or(rest.len());
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let (token, tail) = rest.split_at(end);
+        match token.split_once(',') {
+            Some((first, second)) => {
+                out.push_str(second);
+                out.push(',');
+                out.push_str(first);
+            }
+            None => out.push_str(token),
+        }
+        rest = tail;
+    }
+}
+
+impl Point {
+    /// Parse a point string whose coordinates are in `order`.
+    ///
+    /// `str::parse()` expects `CoordinateOrder::LatitudeLongitude`, as CAP specifies.
+    ///
+    /// ```
+    /// # use oasiscap::geo::{CoordinateOrder, Point};
+    /// assert_eq!(
+    ///     Point::from_str_with_order("-120.14,38.47", CoordinateOrder::LongitudeLatitude).unwrap(),
+    ///     "38.47,-120.14".parse::<Point>().unwrap(),
+    /// );
+    /// // Out-of-range coordinates are still rejected after swapping
+    /// assert!(Point::from_str_with_order("38.47,-120.14", CoordinateOrder::LongitudeLatitude).is_err());
+    /// ```
+    pub fn from_str_with_order(s: &str, order: CoordinateOrder) -> Result<Self, InvalidPointError> {
         match {
             let mut i = s.split(',').map(str::trim).map(f64::from_str);
             (i.next(), i.next(), i.next())
         } {
-            (Some(Ok(latitude)), Some(Ok(longitude)), None) => Point::new(latitude, longitude),
+            (Some(Ok(first)), Some(Ok(second)), None) => match order {
+                CoordinateOrder::LatitudeLongitude => Point::new(first, second),
+                CoordinateOrder::LongitudeLatitude => Point::new(second, first),
+            },
             _ => Err(InvalidPointError::BadFormat(s.into())),
         }
     }
 }
 
+impl FromStr for Point {
+    type Err = InvalidPointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with_order(s, CoordinateOrder::LatitudeLongitude)
+    }
+}
+
 /// A closed polygon, i.e. a geo-referenced polyline where the last point is the first point.
 ///
 /// CAP encodes polygons as strings. This crate represents circles as `Polygon`s.
@@ -136,7 +405,10 @@ impl FromStr for Point {
 ///    "38.47,-120.14 38.34,-119.95 38.52,-119.74 38.62,-119.89 38.47,-120.14",
 /// );
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Since `Point` implements `Eq` and `Hash`, so does `Polygon`. Polygons are equal only if they
+/// have the same points in the same order, starting from the same point.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Polygon(Vec<Point>);
 
 impl IntoIterator for Polygon {
@@ -159,10 +431,235 @@ impl<'a> IntoIterator for &'a Polygon {
 
 impl Polygon {
     /// Returns an iterator over the points in this `Polygon`.
-    pub fn iter(&self) -> impl Iterator<Item = &Point> {
+    ///
+    /// This is the same iterator as `&Polygon: IntoIterator`, so it can be reversed and knows its
+    /// length:
+    ///
+    /// ```
+    /// # use oasiscap::geo::Polygon;
+    /// let polygon: Polygon = "1,1 2,2 3,3 1,1".parse().unwrap();
+    /// assert_eq!(polygon.iter().len(), 4);
+    /// assert_eq!(polygon.iter().rev().nth(1).unwrap().to_string(), "3,3");
+    ///
+    /// let mut count = 0;
+    /// for _point in &polygon {
+    ///     count += 1;
+    /// }
+    /// assert_eq!(count, polygon.into_iter().len());
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, Point> {
         self.0.iter()
     }
 
+    /// Instantiate a `Polygon` from an iterator of points, as for `TryFrom<Vec<Point>>`.
+    ///
+    /// ```
+    /// # use oasiscap::geo::{Point, Polygon};
+    /// let polygon = Polygon::try_from_iter(
+    ///     [(1.0, 1.0), (2.0, 2.0), (3.0, 1.0), (1.0, 1.0)]
+    ///         .into_iter()
+    ///         .map(|(lat, lon)| Point::new(lat, lon).unwrap()),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(polygon.to_string(), "1,1 2,2 3,1 1,1");
+    ///
+    /// // The usual checks apply
+    /// let open = Polygon::try_from_iter(polygon.iter().copied().take(3));
+    /// assert!(open.is_err());
+    /// ```
+    pub fn try_from_iter<I: IntoIterator<Item = Point>>(
+        iter: I,
+    ) -> Result<Self, InvalidPolygonError> {
+        Self::try_from(iter.into_iter().collect::<Vec<_>>())
+    }
+
+    /// Instantiate a `Polygon` from a ring which may omit its closing point.
+    ///
+    /// If the last point differs from the first, a copy of the first point is appended. The
+    /// polygon must still have at least four points, including the closing point.
+    ///
+    /// ```
+    /// # use oasiscap::geo::{InvalidPolygonError, Point, Polygon};
+    /// let points: Vec<Point> = ["1,1", "2,2", "3,1"].iter().map(|p| p.parse().unwrap()).collect();
+    ///
+    /// let polygon = Polygon::from_open_points(points.clone()).unwrap();
+    /// assert_eq!(polygon.to_string(), "1,1 2,2 3,1 1,1");
+    ///
+    /// // An already-closed ring is unchanged
+    /// let closed = polygon.clone().into_iter().collect();
+    /// assert_eq!(Polygon::from_open_points(closed).unwrap(), polygon);
+    ///
+    /// // Two points can't be closed into a polygon
+    /// assert!(matches!(
+    ///     Polygon::from_open_points(points[..2].to_vec()),
+    ///     Err(InvalidPolygonError::TooFewPoints(3))
+    /// ));
+    /// ```
+    pub fn from_open_points(mut points: Vec<Point>) -> Result<Self, InvalidPolygonError> {
+        match (points.first(), points.last()) {
+            (Some(first), Some(last)) if first != last => points.push(*first),
+            _ => {}
+        }
+        Self::try_from(points)
+    }
+
+    /// Returns an iterator over the edges of this `Polygon`, as pairs of consecutive points.
+    ///
+    /// Since the last point of a `Polygon` repeats the first, the last edge runs from the
+    /// second-to-last point back to the start. A polygon of `n` points therefore has `n - 1` edges,
+    /// and the closing point does not produce a zero-length edge of its own.
+    ///
+    /// ```
+    /// # use oasiscap::geo::Polygon;
+    /// let polygon: Polygon = "1,1 2,2 3,1 1,1".parse().unwrap();
+    /// let edges = polygon
+    ///     .edges()
+    ///     .map(|(a, b)| format!("{} {}", a, b))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(edges, vec!["1,1 2,2", "2,2 3,1", "3,1 1,1"]);
+    /// ```
+    pub fn edges(&self) -> impl Iterator<Item = (&Point, &Point)> {
+        self.0.windows(2).map(|pair| (&pair[0], &pair[1]))
+    }
+
+    /// The length of the boundary of this polygon, in kilometers.
+    ///
+    /// This sums the great circle distance along each of `edges()`, on a sphere with the mean
+    /// radius of the Earth, so the closing point contributes exactly one closing edge.
+    ///
+    /// ```
+    /// # use oasiscap::geo::Polygon;
+    /// // A degree of latitude or longitude near the equator is about 111.2 km
+    /// let square: Polygon = "0,0 0,1 1,1 1,0 0,0".parse().unwrap();
+    /// assert!((square.perimeter_km() - 444.76).abs() < 0.01);
+    /// ```
+    pub fn perimeter_km(&self) -> f64 {
+        self.edges()
+            .map(|(a, b)| angle_between(a.to_vector(), b.to_vector()) * EARTH_RADIUS_KM)
+            .sum()
+    }
+
+    /// The bit patterns of each point's latitude and longitude, in order.
+    ///
+    /// Two polygons have the same key if and only if they are equal. `Polygon` implements `Hash`
+    /// itself, but this key is useful for indexing polygons in contexts which need plain integers.
+    ///
+    /// ```
+    /// # use oasiscap::geo::Polygon;
+    /// let a: Polygon = "1,1 2,2 3,3 1,1".parse().unwrap();
+    /// let b: Polygon = "1.0,1.0 2.0,2.0 3.0,3.0 1.0,1.0".parse().unwrap();
+    /// assert_eq!(a.canonical_key(), b.canonical_key());
+    /// assert_eq!(a.canonical_key()[0], (1f64.to_bits(), 1f64.to_bits()));
+    ///
+    /// let unique = vec![a, b].into_iter().collect::<std::collections::HashSet<_>>();
+    /// assert_eq!(unique.len(), 1);
+    /// ```
+    pub fn canonical_key(&self) -> Vec<(u64, u64)> {
+        self.0.iter().map(Point::key).collect()
+    }
+
+    /// Returns a copy of this polygon with points inserted along each edge, so that no edge is
+    /// longer than `max_segment_km`.
+    ///
+    /// CAP does not say how polygon edges connect their points, and renderers commonly draw them as
+    /// straight lines in a projected coordinate system. That is indistinguishable from a great
+    /// circle over short distances, but not across oceans. Inserted points lie on the great circle
+    /// between the original points, which are all retained, so the polygon remains closed.
+    ///
+    /// Distances are computed on a sphere with the mean radius of the Earth. If `max_segment_km`
+    /// is not a positive number, the polygon is returned unchanged. Each edge is split into at most
+    /// 10,000 segments, so an absurdly small `max_segment_km` can't exhaust memory; such edges
+    /// are left longer than requested.
+    ///
+    /// ```
+    /// # use oasiscap::geo::{Point, Polygon};
+    /// let polygon: Polygon = "0,0 0,10 10,10 0,0".parse().unwrap();
+    ///
+    /// // 10° of longitude along the equator is about 1112 km, so it's split into three segments
+    /// let densified = polygon.densify(500.0);
+    /// let points = densified.iter().collect::<Vec<_>>();
+    /// assert_eq!(points[0], &Point::new(0.0, 0.0).unwrap());
+    /// assert_eq!(points[1].latitude(), 0.0);
+    /// assert!((points[1].longitude() - 10.0 / 3.0).abs() < 1e-9);
+    /// assert_eq!(points[3], &Point::new(0.0, 10.0).unwrap());
+    /// assert_eq!(points.first(), points.last());
+    ///
+    /// assert_eq!(polygon.densify(5000.0), polygon);
+    ///
+    /// // Tiny steps are limited to 10,000 segments per edge
+    /// assert_eq!(polygon.densify(1e-9).iter().len(), 3 * 10_000 + 1);
+    /// ```
+    pub fn densify(&self, max_segment_km: f64) -> Polygon {
+        if max_segment_km.is_nan() || max_segment_km <= 0.0 {
+            return self.clone();
+        }
+
+        let mut points = vec![self.0[0]];
+        for pair in self.0.windows(2) {
+            let (from, to) = (pair[0].to_vector(), pair[1].to_vector());
+            let angle = angle_between(from, to);
+            let segments = (angle * EARTH_RADIUS_KM / max_segment_km)
+                .ceil()
+                .min(MAX_DENSIFY_SEGMENTS);
+            // Antipodal points don't define a unique great circle, so leave them alone
+            if segments > 1.0 && angle.sin() > f64::EPSILON {
+                let segments = segments as usize;
+                for i in 1..segments {
+                    let fraction = i as f64 / segments as f64;
+                    let a = ((1.0 - fraction) * angle).sin() / angle.sin();
+                    let b = (fraction * angle).sin() / angle.sin();
+                    points.push(Point::from_vector([
+                        a * from[0] + b * to[0],
+                        a * from[1] + b * to[1],
+                        a * from[2] + b * to[2],
+                    ]));
+                }
+            }
+            points.push(pair[1]);
+        }
+
+        Polygon(points)
+    }
+
+    /// Returns a copy of this polygon with every point rounded to `decimals` decimal places, as by
+    /// `Point::round()`.
+    ///
+    /// The last point is set to the first point after rounding, so the polygon remains closed.
+    /// This is useful for normalizing alerts before comparing or caching them, since some systems
+    /// produce coordinates with far more precision than is meaningful. Serialization is otherwise
+    /// unaffected.
+    ///
+    /// ```
+    /// # use oasiscap::geo::Polygon;
+    /// let polygon: Polygon = "38.47,-120.14 38.34,-119.95 38.52,-119.74 38.47,-120.14"
+    ///     .parse()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     polygon.with_precision(1).to_string(),
+    ///     "38.5,-120.1 38.3,-120 38.5,-119.7 38.5,-120.1"
+    /// );
+    ///
+    /// // -0 equals 0, so this polygon is closed even though its first and last points are written
+    /// // differently. After rounding, both are written like the first point.
+    /// let polygon: Polygon = "-0,-120.14 38.34,-119.95 38.52,-119.74 0,-120.14"
+    ///     .parse()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     polygon.with_precision(1).to_string(),
+    ///     "-0,-120.1 38.3,-120 38.5,-119.7 -0,-120.1"
+    /// );
+    /// ```
+    pub fn with_precision(&self, decimals: u8) -> Polygon {
+        let mut points = self
+            .0
+            .iter()
+            .map(|point| point.round(decimals))
+            .collect::<Vec<_>>();
+        let first = points[0];
+        *points.last_mut().unwrap() = first;
+        Polygon(points)
+    }
+
     // Deserialize, but treat `<polygon></polygon>` the same as ``.
     pub(crate) fn deserialize_optional<'de, D>(deserializer: D) -> Result<Vec<Polygon>, D::Error>
     where
@@ -264,7 +761,12 @@ impl FromStr for Polygon {
 
 /// A geo-referenced circle with a given center point and radius.
 ///
-/// CAP encodes circles as strings. This crate represents circles as `Circle`s.
+/// CAP encodes circles as strings. This crate represents circles as `Circle`s. Every CAP version
+/// shares this type, so `v1dot0::Area`, `v1dot1::Area`, and `v1dot2::Area` all hold the same
+/// validated `Circle`s and no conversion between them is needed.
+///
+/// Unlike `Point` and `Polygon`, `Circle` does not implement `Eq` or `Hash`, since its public
+/// `radius` field can be set to NaN.
 ///
 /// # Example
 ///
@@ -320,8 +822,53 @@ impl Circle {
             Err(InvalidCircleError::RadiusTooLarge(radius))
         }
     }
+
+    /// Instantiate a new `Circle` around a given `center` with a specified `radius` in statute
+    /// miles.
+    ///
+    /// The radius is converted to kilometers and validated as for `Circle::new()`.
+    ///
+    /// ```
+    /// # use oasiscap::geo::{Circle, Point};
+    /// let center = Point::new(32.9525, -115.5527).unwrap();
+    /// let circle = Circle::from_center_miles(center, 10.0).unwrap();
+    /// assert_eq!(circle.radius, 16.09344);
+    /// assert_eq!(circle.radius_miles(), 10.0);
+    ///
+    /// assert!(Circle::from_center_miles(center, 15000.0).is_err());
+    /// ```
+    pub fn from_center_miles(center: Point, miles: f64) -> Result<Self, InvalidCircleError> {
+        Self::new(center, miles * KILOMETERS_PER_MILE)
+    }
+
+    /// Instantiate a new `Circle` around a given `center` with a specified `radius` in nautical
+    /// miles.
+    ///
+    /// The radius is converted to kilometers and validated as for `Circle::new()`.
+    pub fn from_center_nautical_miles(
+        center: Point,
+        nautical_miles: f64,
+    ) -> Result<Self, InvalidCircleError> {
+        Self::new(center, nautical_miles * KILOMETERS_PER_NAUTICAL_MILE)
+    }
+
+    /// The radius of the circle, in statute miles
+    pub fn radius_miles(&self) -> f64 {
+        self.radius / KILOMETERS_PER_MILE
+    }
+
+    /// The radius of the circle, in nautical miles
+    pub fn radius_nautical_miles(&self) -> f64 {
+        self.radius / KILOMETERS_PER_NAUTICAL_MILE
+    }
 }
 
+/// The length of an international statute mile, exactly.
+const KILOMETERS_PER_MILE: f64 = 1.609344;
+
+/// The length of an international nautical mile, exactly.
+const KILOMETERS_PER_NAUTICAL_MILE: f64 = 1.852;
+
 impl std::fmt::Display for Circle {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(
@@ -388,3 +935,178 @@ impl FromStr for Circle {
         Self::new(center, radius)
     }
 }
+
+/// Either a `Polygon` or a `Circle`, as yielded by `Alert::shapes()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    /// A polygon
+    Polygon(Polygon),
+    /// A circle
+    Circle(Circle),
+}
+
+impl From<Polygon> for Shape {
+    fn from(polygon: Polygon) -> Self {
+        Shape::Polygon(polygon)
+    }
+}
+
+impl From<Circle> for Shape {
+    fn from(circle: Circle) -> Self {
+        Shape::Circle(circle)
+    }
+}
+
+impl Shape {
+    /// Returns `true` if this shape's bounding box overlaps the box with corners `sw` and `ne`.
+    ///
+    /// This is a cheap, conservative test: shapes which do not intersect the box may still return
+    /// `true`, but shapes which do always return `true`. If `sw` is east of `ne`, the box is taken
+    /// to cross the antimeridian. A circle's bounds are computed on a spherical Earth, and cover
+    /// all longitudes if it's near a pole or crosses the antimeridian.
+    pub fn intersects_bbox(&self, sw: Point, ne: Point) -> bool {
+        let (south, west, north, east) = self.bounds();
+        let latitude = south <= ne.latitude() && north >= sw.latitude();
+        let longitude = if sw.longitude() <= ne.longitude() {
+            west <= ne.longitude() && east >= sw.longitude()
+        } else {
+            west <= ne.longitude() || east >= sw.longitude()
+        };
+        latitude && longitude
+    }
+
+    /// Returns `true` if this shape and `filter` have any point in common.
+    ///
+    /// Polygon edges are treated as straight lines in the plane of latitude and longitude, so
+    /// results are not meaningful for polygons which cross the antimeridian. The distance from a
+    /// circle's center to each edge is measured in a plane tangent to the Earth at that center,
+    /// which is accurate for circles of the sizes found in alerts.
+    pub fn intersects_polygon(&self, filter: &Polygon) -> bool {
+        match self {
+            Shape::Polygon(polygon) => {
+                polygon.edges().any(|(a, b)| {
+                    filter
+                        .edges()
+                        .any(|(c, d)| segments_intersect(a.xy(), b.xy(), c.xy(), d.xy()))
+                }) || filter.contains_planar(&polygon.0[0])
+                    || polygon.contains_planar(&filter.0[0])
+            }
+            Shape::Circle(circle) => {
+                filter.contains_planar(&circle.center)
+                    || filter
+                        .edges()
+                        .any(|(a, b)| distance_to_segment_km(&circle.center, a, b) <= circle.radius)
+            }
+        }
+    }
+
+    /// The `(south, west, north, east)` bounds of this shape, in degrees.
+    fn bounds(&self) -> (f64, f64, f64, f64) {
+        match self {
+            Shape::Polygon(polygon) => polygon.iter().fold(
+                (
+                    f64::INFINITY,
+                    f64::INFINITY,
+                    f64::NEG_INFINITY,
+                    f64::NEG_INFINITY,
+                ),
+                |(south, west, north, east), point| {
+                    (
+                        south.min(point.latitude()),
+                        west.min(point.longitude()),
+                        north.max(point.latitude()),
+                        east.max(point.longitude()),
+                    )
+                },
+            ),
+            Shape::Circle(circle) => {
+                let (latitude, longitude) = (circle.center.latitude(), circle.center.longitude());
+                let degrees = (circle.radius / EARTH_RADIUS_KM).to_degrees();
+                let (south, north) = (latitude - degrees, latitude + degrees);
+                if south <= -90.0 || north >= 90.0 {
+                    return (south.max(-90.0), -180.0, north.min(90.0), 180.0);
+                }
+
+                // The widest point of the circle is no further from the pole than its edge
+                let widest = latitude.abs() + degrees;
+                let degrees = degrees / widest.to_radians().cos();
+                let (west, east) = (longitude - degrees, longitude + degrees);
+                if west < -180.0 || east > 180.0 {
+                    (south, -180.0, north, 180.0)
+                } else {
+                    (south, west, north, east)
+                }
+            }
+        }
+    }
+}
+
+impl Point {
+    /// This point as planar `(x, y)` coordinates, i.e. `(longitude, latitude)`.
+    fn xy(&self) -> (f64, f64) {
+        (self.longitude, self.latitude)
+    }
+}
+
+impl Polygon {
+    /// Returns `true` if `point` is inside this polygon, treating edges as straight lines in the
+    /// plane of latitude and longitude.
+    fn contains_planar(&self, point: &Point) -> bool {
+        let (x, y) = point.xy();
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            let ((ax, ay), (bx, by)) = (a.xy(), b.xy());
+            if (ay > y) != (by > y) && x < (bx - ax) * (y - ay) / (by - ay) + ax {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+}
+
+/// Returns `true` if segment `a`-`b` and segment `c`-`d` have any point in common.
+fn segments_intersect(a: (f64, f64), b: (f64, f64), c: (f64, f64), d: (f64, f64)) -> bool {
+    // The sign of the cross product tells which side of `p`-`q` the point `r` is on
+    let orientation = |p: (f64, f64), q: (f64, f64), r: (f64, f64)| {
+        let cross = (q.0 - p.0) * (r.1 - p.1) - (q.1 - p.1) * (r.0 - p.0);
+        (cross > 0.0) as i8 - (cross < 0.0) as i8
+    };
+    // Given that `r` is collinear with `p`-`q`, is it within the segment?
+    let within = |p: (f64, f64), q: (f64, f64), r: (f64, f64)| {
+        r.0 >= p.0.min(q.0) && r.0 <= p.0.max(q.0) && r.1 >= p.1.min(q.1) && r.1 <= p.1.max(q.1)
+    };
+
+    let (o1, o2) = (orientation(a, b, c), orientation(a, b, d));
+    let (o3, o4) = (orientation(c, d, a), orientation(c, d, b));
+    (o1 != o2 && o3 != o4 && o1 != 0 && o2 != 0 && o3 != 0 && o4 != 0)
+        || (o1 == 0 && within(a, b, c))
+        || (o2 == 0 && within(a, b, d))
+        || (o3 == 0 && within(c, d, a))
+        || (o4 == 0 && within(c, d, b))
+}
+
+/// The distance from `point` to the segment `a`-`b`, in kilometers.
+///
+/// The segment is projected onto a plane tangent to the Earth at `point`.
+fn distance_to_segment_km(point: &Point, a: &Point, b: &Point) -> f64 {
+    let km_per_degree = EARTH_RADIUS_KM.to_radians();
+    let scale = point.latitude.to_radians().cos();
+    let project = |p: &Point| {
+        (
+            (p.longitude - point.longitude) * scale * km_per_degree,
+            (p.latitude - point.latitude) * km_per_degree,
+        )
+    };
+    let ((ax, ay), (bx, by)) = (project(a), project(b));
+
+    // Find the closest point on the segment to the origin
+    let (dx, dy) = (bx - ax, by - ay);
+    let length_squared = dx * dx + dy * dy;
+    let t = if length_squared > 0.0 {
+        (-(ax * dx + ay * dy) / length_squared).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let (x, y) = (ax + t * dx, ay + t * dy);
+    (x * x + y * y).sqrt()
+}
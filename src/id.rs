@@ -35,7 +35,7 @@ use std::ops::Deref;
 ///
 /// assert!(Id::new(" new-does-not ").is_err());
 /// ```
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Id(String);
 
 impl Id {
@@ -57,6 +57,44 @@ impl Id {
             Ok(Self(string))
         }
     }
+
+    /// Returns whether this `Id` resembles an email address or a dotted domain name.
+    ///
+    /// CAP says that an alert's `sender` should be globally unique, "e.g., may be based on an
+    /// Internet domain name". This is a heuristic for originators who want to warn about senders
+    /// like `me` which are obviously not. It does not affect which `Id`s are valid, and a `false`
+    /// result does not make an alert non-conforming.
+    ///
+    /// ```rust
+    /// # use oasiscap::id::Id;
+    /// assert!(Id::new("hsas@dhs.gov").unwrap().looks_like_sender());
+    /// assert!(Id::new("w-nws.webmaster@noaa.gov").unwrap().looks_like_sender());
+    /// assert!(Id::new("KSTO1055887203.example.org").unwrap().looks_like_sender());
+    ///
+    /// assert!(!Id::new("me").unwrap().looks_like_sender());
+    /// assert!(!Id::new("me@localhost").unwrap().looks_like_sender());
+    /// assert!(!Id::new("192.168.0.1").unwrap().looks_like_sender());
+    /// assert!(!Id::new("@example.org").unwrap().looks_like_sender());
+    /// ```
+    pub fn looks_like_sender(&self) -> bool {
+        let domain = match self.0.rsplit_once('@') {
+            Some((local, domain)) if !local.is_empty() => domain,
+            Some(_) => return false,
+            None => self.as_str(),
+        };
+
+        let labels: Vec<&str> = domain.split('.').collect();
+        let tld = labels[labels.len() - 1];
+        labels.len() >= 2
+            && labels.iter().all(|label| {
+                !label.is_empty()
+                    && !label.starts_with('-')
+                    && !label.ends_with('-')
+                    && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            })
+            && tld.len() >= 2
+            && tld.chars().all(|c| c.is_ascii_alphabetic())
+    }
 }
 
 impl TryFrom<String> for Id {
@@ -133,6 +133,62 @@ impl Language {
     pub fn is_empty(&self) -> bool {
         self.0.is_none()
     }
+
+    /// Returns `true` if the `Language` matches a language range, according to [RFC 4647] basic
+    /// filtering.
+    ///
+    /// A range matches if it is equal to the language or to a prefix of it ending at a `-`,
+    /// ignoring case. The range `*` matches every language. An unspecified `Language` is matched as
+    /// `en-US`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use oasiscap::language::Language;
+    /// let language: Language = "zh-Hant-TW".parse().unwrap();
+    /// assert!(language.matches("zh"));
+    /// assert!(language.matches("zh-hant"));
+    /// assert!(language.matches("ZH-Hant-TW"));
+    /// assert!(language.matches("*"));
+    /// assert!(!language.matches("zh-Han"));
+    /// assert!(!language.matches("zh-Hans"));
+    ///
+    /// // Unspecified languages are en-US
+    /// assert!(Language::default().matches("en"));
+    /// assert!(!Language::default().matches("en-GB"));
+    /// ```
+    ///
+    /// [RFC 4647]: https://datatracker.ietf.org/doc/html/rfc4647#section-3.3.1
+    pub fn matches(&self, range: &str) -> bool {
+        let language = self.as_str();
+        range == "*"
+            || language.eq_ignore_ascii_case(range)
+            || (language.len() > range.len()
+                && language.as_bytes()[range.len()] == b'-'
+                && language[..range.len()].eq_ignore_ascii_case(range))
+    }
+}
+
+/// The `items` whose language matches `range`, or else those matching `en-US`, as described by
+/// `v1dot2::Alert::info_for_language()`.
+pub(crate) fn select<'a, T>(
+    items: &'a [T],
+    language: impl Fn(&T) -> &Language,
+    range: &str,
+) -> Vec<&'a T> {
+    let matching = |range: &str| -> Vec<&'a T> {
+        items
+            .iter()
+            .filter(|item| language(item).matches(range))
+            .collect()
+    };
+
+    let selected = matching(range);
+    if selected.is_empty() {
+        matching("en-US")
+    } else {
+        selected
+    }
 }
 
 impl AsRef<str> for Language {
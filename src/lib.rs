@@ -111,6 +111,10 @@
 //! particularly for common implementation mistakes which have reasonable and unambiguous
 //! interpretations.
 //!
+//! Conformance testers who need to flag such messages can use
+//! `oasiscap::v1dot2::Alert::from_str_strict()`, which parses in the same way but then rejects any
+//! alert which violates the requirements in section 3.
+//!
 //! # Performance
 //!
 //! `oasiscap` prioritizes being correct over being fast, but it is still reasonably fast. On an
@@ -122,6 +126,15 @@
 //!
 //! Clone the repository and run `cargo bench` to see how it performs in your environment.
 //!
+//! Preserving unknown extension elements is opt-in because it costs an extra pass over the
+//! document: `v1dot2::Alert::from_str_preserving_extensions()` reads its input twice, and
+//! `v1dot2::Alert::write_xml_with_extensions()` re-reads its own output when there are extensions
+//! to insert.
+//!
+//! Parsed alerts own all their text. `xml-rs`, which underlies `xml_serde`, produces an owned
+//! `String` for every run of text in a document, so a view type borrowing text from the input
+//! would save none of those allocations and is not offered.
+//!
 //! # Protocol Buffers
 //!
 //! Google Public Alerts defines a [CAP Protocol Buffers representation], under the Java package
@@ -177,7 +190,7 @@ extern crate core;
 use serde::{Deserialize, Serialize};
 
 mod datetime;
-pub use datetime::DateTime;
+pub use datetime::{DateTime, TzOrigin};
 
 pub mod digest;
 
@@ -185,15 +198,32 @@ mod embedded_data;
 pub use embedded_data::EmbeddedContent;
 
 pub mod delimited_items;
+#[cfg(feature = "feed")]
+pub mod feed;
 pub mod geo;
 pub mod id;
 pub mod language;
 pub mod map;
 pub mod references;
 
+mod parse_error;
+pub use parse_error::{Error, ParseError};
+
+mod rewrite;
+pub use rewrite::XmlOutputOptions;
+
+mod stream;
+pub use stream::StreamError;
+
+mod unknown_value;
+pub use unknown_value::UnknownValueError;
+
 mod alert;
 pub use alert::Alert;
 
+mod upgrade;
+pub use upgrade::{UpgradeChange, UpgradeReport};
+
 pub mod v1dot0;
 pub mod v1dot1;
 pub mod v1dot2;
@@ -201,6 +231,13 @@ pub mod v1dot2;
 #[cfg(feature = "prost")]
 pub mod protobuf;
 
+#[cfg(feature = "test-util")]
+pub mod testing;
+
+mod number;
+mod subtree;
+
 pub(crate) mod url;
+pub use url::UrlPolicy;
 
 pub use ::url::Url;
@@ -98,6 +98,27 @@ impl<E: Entry> Map<E> {
             .map(|e| e.value())
     }
 
+    /// Get the first value for this key, if any, parsed as `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let map: oasiscap::v1dot2::Map = [
+    ///     ("EventPreliminaryMagnitude", "7.5"),
+    ///     ("Depth", "11.8 mi."),
+    /// ].into_iter().collect();
+    ///
+    /// assert_eq!(map.get_parsed::<f64>("EventPreliminaryMagnitude"), Some(Ok(7.5)));
+    /// assert!(matches!(map.get_parsed::<f64>("Depth"), Some(Err(_))));
+    /// assert!(map.get_parsed::<f64>("Magnitude").is_none());
+    /// ```
+    pub fn get_parsed<T: std::str::FromStr>(
+        &self,
+        value_name: impl AsRef<str>,
+    ) -> Option<Result<T, T::Err>> {
+        self.get(value_name).map(str::parse)
+    }
+
     /// Iterate over all the values for a given key.
     ///
     /// # Example
@@ -121,6 +142,32 @@ impl<E: Entry> Map<E> {
         })
     }
 
+    /// List the keys which appear more than once, in order of their first appearance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let map: oasiscap::v1dot2::Map = [
+    ///     ("foo", "bar"),
+    ///     ("quxx", "flummox"),
+    ///     ("foo", "baz"),
+    ///     ("quxx", "flummox"),
+    ///     ("waldo", "fred"),
+    /// ].into_iter().collect();
+    ///
+    /// assert_eq!(map.duplicate_keys(), vec!["foo", "quxx"]);
+    /// ```
+    pub fn duplicate_keys(&self) -> Vec<&str> {
+        let mut keys = Vec::new();
+        for (i, entry) in self.0.iter().enumerate() {
+            let key = entry.value_name();
+            if !keys.contains(&key) && self.0[i + 1..].iter().any(|e| e.value_name() == key) {
+                keys.push(key);
+            }
+        }
+        keys
+    }
+
     /// Push a new key-value entry onto an existing map.
     ///
     /// # Example
@@ -155,6 +202,30 @@ impl<E: Entry> Map<E> {
     pub fn iter(&self) -> Iter<E> {
         Iter(self.0.iter())
     }
+
+    /// Returns `true` if both maps contain the same entries, regardless of their order.
+    ///
+    /// Duplicate entries are significant, i.e. the maps are compared as multisets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let a: oasiscap::v1dot2::Map = [("foo", "bar"), ("foo", "baz")].into_iter().collect();
+    /// let b: oasiscap::v1dot2::Map = [("foo", "baz"), ("foo", "bar")].into_iter().collect();
+    /// let c: oasiscap::v1dot2::Map = [("foo", "baz"), ("foo", "baz")].into_iter().collect();
+    ///
+    /// assert_ne!(a, b);
+    /// assert!(a.eq_unordered(&b));
+    /// assert!(!a.eq_unordered(&c));
+    /// ```
+    pub fn eq_unordered(&self, other: &Self) -> bool {
+        fn sorted<E: Entry>(map: &Map<E>) -> Vec<(&str, &str)> {
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries.sort_unstable();
+            entries
+        }
+        self.len() == other.len() && sorted(self) == sorted(other)
+    }
 }
 
 impl<E> Default for Map<E> {
@@ -0,0 +1,25 @@
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Deserialize an optional number, ignoring surrounding whitespace.
+///
+/// The schema types `size`, `altitude`, and `ceiling` as `xs:integer` and `xs:decimal`, whose
+/// whitespace facet is `collapse`, but some producers pad these values anyway.
+pub(crate) fn deserialize_optional<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    match <Option<Cow<str>>>::deserialize(deserializer)? {
+        Some(string) => string
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|e| D::Error::custom(format!("invalid number {:?}: {}", string, e))),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,542 @@
+use xml::common::Position;
+use xml::reader::{EventReader, XmlEvent};
+
+/// CAP elements which may occur more than once within their parent, and are therefore indexed
+/// when describing a location.
+const REPEATABLE: &[&str] = &[
+    "code",
+    "info",
+    "category",
+    "responseType",
+    "eventCode",
+    "parameter",
+    "resource",
+    "area",
+    "polygon",
+    "circle",
+    "geocode",
+];
+
+/// The error returned when an alert cannot be parsed.
+///
+/// This describes the underlying deserialization error, which is available through
+/// `std::error::Error::source()`, together with a best-effort indication of where in the document
+/// the problem lies. The location is reconstructed after the fact, either from the
+/// point at which the XML stopped being well-formed, or by finding the element or value named in
+/// the error message, so it may be unavailable.
+///
+/// # Example
+///
+/// ```
+/// # let input = include_str!("../fixtures/v1dot2_appendix_adot1.xml");
+/// // let input: &str = /* CAP v1.2 appendix A.1 */;
+/// let input = input.replace("<category>Security</category>", "<category>Zombies</category>");
+///
+/// let err = input.parse::<oasiscap::v1dot2::Alert>().unwrap_err();
+/// assert_eq!(err.path(), Some("info[0].category[0]"));
+/// assert_eq!(&input[err.offset().unwrap()..][..7], "Zombies");
+/// ```
+#[derive(Debug)]
+pub struct ParseError {
+    source: xml_serde::Error,
+    offset: Option<usize>,
+    path: Option<String>,
+}
+
+impl ParseError {
+    /// Locate `source` within `xml`, the document which failed to parse.
+    pub(crate) fn new(xml: &str, source: xml_serde::Error) -> Self {
+        let (offset, path) = match locate(xml, &source.to_string()) {
+            Some((offset, path)) => (Some(offset), Some(path)),
+            None => (None, None),
+        };
+        Self {
+            source,
+            offset,
+            path,
+        }
+    }
+
+    /// The approximate byte offset of the problem within the document, if known.
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// The path to the element containing the problem, if known.
+    ///
+    /// Paths are relative to the `<alert>` element and use CAP element names, indexing elements
+    /// which may repeat, e.g. `info[0].area[2].polygon[0]`.
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.source)?;
+        if let Some(path) = &self.path {
+            write!(f, " at {}", path)?;
+        }
+        if let Some(offset) = self.offset {
+            write!(f, " (byte {})", offset)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// The namespaces of the CAP versions which `crate::Alert` can parse.
+const SUPPORTED_NAMESPACES: &[&str] = &[
+    "http://www.incident.com/cap/1.0",
+    "urn:oasis:names:tc:emergency:cap:1.1",
+    "urn:oasis:names:tc:emergency:cap:1.2",
+];
+
+/// The error returned when a document cannot be parsed as an `oasiscap::Alert`, or when an alert
+/// cannot be written as XML.
+///
+/// # Example
+///
+/// ```
+/// # let input = include_str!("../fixtures/v1dot2_appendix_adot1.xml");
+/// // let input: &str = /* CAP v1.2 appendix A.1 */;
+/// let input = input.replace("emergency:cap:1.2", "emergency:cap:2.0");
+///
+/// match input.parse::<oasiscap::Alert>() {
+///     Err(oasiscap::Error::UnsupportedNamespace(namespace)) => {
+///         assert_eq!(namespace, "urn:oasis:names:tc:emergency:cap:2.0");
+///     }
+///     other => panic!("unexpected result: {:?}", other),
+/// }
+/// ```
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    /// The document is not a valid alert.
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+
+    /// The root element of the document is not in the CAP v1.0, v1.1, or v1.2 namespace, so it's
+    /// not an alert of any version this crate supports.
+    ///
+    /// This is reported as soon as the root element is read, even if the rest of the document is
+    /// not well-formed.
+    #[error("unsupported namespace {0:?}: expected CAP v1.0, v1.1, or v1.2")]
+    UnsupportedNamespace(
+        /// The namespace of the root element, which is empty if it has no namespace
+        String,
+    ),
+
+    /// The root element of the document is in a supported CAP namespace, but not the one required
+    /// by the caller, e.g. a CAP v1.1 alert passed to `v1dot2::Alert::from_str_assume_v1dot2()`.
+    #[error("wrong namespace {found:?}: expected {expected:?}")]
+    WrongNamespace {
+        /// The namespace the caller required
+        expected: &'static str,
+        /// The namespace of the root element, which is empty if it has no namespace
+        found: String,
+    },
+
+    /// The alert could not be written as XML.
+    #[error("error writing alert as XML: {0}")]
+    Serialize(
+        /// The underlying error
+        #[source]
+        Box<dyn std::error::Error + Send + Sync>,
+    ),
+}
+
+impl Error {
+    /// Classify `source`, the error from parsing `xml`.
+    pub(crate) fn new(xml: &str, source: ParseError) -> Self {
+        match root_namespace(xml) {
+            Some(namespace) if !SUPPORTED_NAMESPACES.contains(&namespace.as_str()) => {
+                Error::UnsupportedNamespace(namespace)
+            }
+            _ => Error::Parse(source),
+        }
+    }
+
+    /// Classify `source`, the error from parsing `xml` as an alert in `expected` namespace.
+    pub(crate) fn expecting(xml: &str, expected: &'static str, source: ParseError) -> Self {
+        match root_namespace(xml) {
+            Some(found) if found != expected => {
+                if SUPPORTED_NAMESPACES.contains(&found.as_str()) {
+                    Error::WrongNamespace { expected, found }
+                } else {
+                    Error::UnsupportedNamespace(found)
+                }
+            }
+            _ => Error::Parse(source),
+        }
+    }
+
+    /// The approximate byte offset of the problem within the document, if known.
+    ///
+    /// See `ParseError::offset()`.
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            Error::Parse(e) => e.offset(),
+            Error::UnsupportedNamespace(_) | Error::WrongNamespace { .. } | Error::Serialize(_) => {
+                None
+            }
+        }
+    }
+
+    /// The path to the element containing the problem, if known.
+    ///
+    /// See `ParseError::path()`.
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            Error::Parse(e) => e.path(),
+            Error::UnsupportedNamespace(_) | Error::WrongNamespace { .. } | Error::Serialize(_) => {
+                None
+            }
+        }
+    }
+}
+
+/// The namespace of the root element of `xml`, or `None` if it can't be read.
+///
+/// A root element without a namespace has an empty namespace. Only the document up to the root
+/// element's start tag is read, so the rest of the document need not be well-formed.
+fn root_namespace(xml: &str) -> Option<String> {
+    for event in EventReader::new(xml.trim_start().as_bytes()) {
+        match event {
+            Ok(XmlEvent::StartElement { name, .. }) => {
+                return Some(name.namespace.unwrap_or_default())
+            }
+            Ok(_) => {}
+            Err(_) => return None,
+        }
+    }
+    None
+}
+
+/// Parse `xml` into `T`, locating any failure.
+///
+/// Leading whitespace, comments, and processing instructions before the root element are skipped.
+pub(crate) fn from_str<T: serde::de::DeserializeOwned>(xml: &str) -> Result<T, ParseError> {
+    let document = match root_element_start(xml) {
+        Some(start) => &xml[start..],
+        None => xml,
+    };
+    xml_serde::from_str(document).map_err(|e| ParseError::new(xml, e))
+}
+
+/// Serialize `value` as XML, reporting any failure as an `Error`.
+pub(crate) fn to_string<T: serde::Serialize>(value: &T) -> Result<String, Error> {
+    xml_serde::to_string(value).map_err(|e| Error::Serialize(e.into()))
+}
+
+/// Find the offset of the root element, if it's preceded by anything besides the XML declaration.
+///
+/// Returns `None` if the document is already in the expected form, or if its prolog can't be
+/// skipped, in which case it should be parsed as-is.
+fn root_element_start(xml: &str) -> Option<usize> {
+    let mut offset = 0;
+    let mut skipped = false;
+    loop {
+        let rest = &xml[offset..];
+        let trimmed = rest.trim_start();
+        if offset == 0 && trimmed.len() != rest.len() {
+            // An XML declaration must come first, but some producers emit blank lines before it
+            skipped = true;
+        }
+        offset += rest.len() - trimmed.len();
+
+        let end = if let Some(comment) = trimmed.strip_prefix("<!--") {
+            skipped = true;
+            "<!--".len() + comment.find("-->")? + "-->".len()
+        } else if let Some(instruction) = trimmed.strip_prefix("<?") {
+            let is_declaration = instruction
+                .strip_prefix("xml")
+                .is_some_and(|s| s.starts_with(char::is_whitespace));
+            if !(is_declaration && offset == 0) {
+                skipped = true;
+            }
+            "<?".len() + instruction.find("?>")? + "?>".len()
+        } else if trimmed.starts_with('<') && !trimmed.starts_with("<!") {
+            return skipped.then_some(offset);
+        } else {
+            return None;
+        };
+        offset += end;
+    }
+}
+
+/// Find the offset and path of the problem described by `message`.
+///
+/// If the document is not well-formed, this is the point where it stops being so. Otherwise, it's
+/// the element or value named by the first quotation in the error message, which is the offending
+/// one. Later quotations, like the alternatives in "expected one of `…`", may well occur elsewhere
+/// in the document and are ignored. Values are only matched as the entire text of an element
+/// without children, so that a word within some other text isn't mistaken for the problem. If the
+/// message also names the element being read, values are only matched within that element.
+fn locate(xml: &str, message: &str) -> Option<(usize, String)> {
+    let offending = first_quoted(message);
+    let element = reported_element(message);
+    // A missing field is named in the message, but finding it elsewhere would be misleading
+    let match_elements = !message.starts_with("missing field");
+
+    let mut reader = EventReader::new(xml.as_bytes());
+    // One entry per open element: its path segment, and the number of each repeatable child seen
+    let mut stack: Vec<(String, Vec<(String, usize)>)> = Vec::new();
+    let path = |stack: &[(String, Vec<(String, usize)>)]| {
+        stack
+            .iter()
+            .skip(1)
+            .map(|(segment, _)| segment.as_str())
+            .collect::<Vec<_>>()
+            .join(".")
+    };
+
+    // Text matching `offending`, which is the problem if its element ends without any children
+    let mut candidate = None;
+
+    loop {
+        let event = match reader.next() {
+            Ok(event) => event,
+            Err(e) => {
+                let offset = byte_offset(xml, e.position().row, e.position().column);
+                return Some((offset, path(&stack)));
+            }
+        };
+        let offset = || byte_offset(xml, reader.position().row, reader.position().column);
+
+        let text_candidate = candidate.take();
+        match event {
+            XmlEvent::StartElement { name, .. } => {
+                let name = name.local_name;
+                let segment = match stack.last_mut() {
+                    Some((_, children)) if REPEATABLE.contains(&name.as_str()) => {
+                        let index = match children.iter_mut().find(|(child, _)| child == &name) {
+                            Some((_, count)) => {
+                                *count += 1;
+                                *count - 1
+                            }
+                            None => {
+                                children.push((name.clone(), 1));
+                                0
+                            }
+                        };
+                        format!("{}[{}]", name, index)
+                    }
+                    _ => name.clone(),
+                };
+                stack.push((segment, Vec::new()));
+                if match_elements && offending == Some(name.as_str()) {
+                    return Some((offset(), path(&stack)));
+                }
+            }
+            XmlEvent::EndElement { .. } => {
+                if text_candidate.is_some() {
+                    return text_candidate;
+                }
+                stack.pop();
+            }
+            XmlEvent::Characters(text) | XmlEvent::CData(text) => {
+                let in_element = match (element, stack.last()) {
+                    (Some(element), Some((segment, _))) => {
+                        segment.split('[').next() == Some(element)
+                    }
+                    _ => true,
+                };
+                if in_element && offending == Some(text.trim()) {
+                    // Point at the text itself, rather than at any leading whitespace
+                    let offset = offset();
+                    let leading = xml[offset..].len() - xml[offset..].trim_start().len();
+                    candidate = Some((offset + leading, path(&stack)));
+                }
+            }
+            XmlEvent::Whitespace(_) | XmlEvent::Comment(_) => {
+                // Neither makes the preceding text any less the whole content of its element
+                candidate = text_candidate;
+            }
+            XmlEvent::EndDocument => return None,
+            _ => {}
+        }
+    }
+}
+
+/// The local name of a CAP element named in `message`, as `xml_serde` writes it in Clark notation,
+/// e.g. `event` for `{urn:oasis:names:tc:emergency:cap:1.2;}cap:event`.
+fn reported_element(message: &str) -> Option<&str> {
+    let start = message.find("}cap:").map(|i| i + "}cap:".len())?;
+    let name = &message[start..];
+    let end = name
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(name.len());
+    (end > 0).then_some(&name[..end])
+}
+
+/// The first substring of `message` enclosed in backticks or double quotes.
+fn first_quoted(message: &str) -> Option<&str> {
+    let start = message.find(['`', '"'])?;
+    let delimiter = message[start..].chars().next()?;
+    let rest = &message[start + 1..];
+    let quoted = &rest[..rest.find(delimiter)?];
+    (!quoted.is_empty()).then_some(quoted)
+}
+
+/// Convert a zero-based row and column (in characters) into a byte offset.
+pub(crate) fn byte_offset(xml: &str, row: u64, column: u64) -> usize {
+    let mut offset = 0;
+    for line in xml.split_inclusive('\n').take(row as usize) {
+        offset += line.len();
+    }
+    offset
+        + xml[offset..]
+            .char_indices()
+            .nth(column as usize)
+            .map_or(xml.len() - offset, |(i, _)| i)
+}
+
+#[cfg(test)]
+mod tests {
+    const INPUT: &str = include_str!("../fixtures/v1dot2_appendix_adot2.xml");
+
+    #[test]
+    fn malformed() {
+        let input = INPUT.replacen("</polygon>", "</polygone>", 1);
+        let err = input.parse::<crate::Alert>().unwrap_err();
+        assert_eq!(err.path(), Some("info[0].area[0].polygon[0]"));
+        // xml-rs notices the problem somewhere within the mismatched end tag
+        let start = input.find("</polygone>").unwrap();
+        let end = start + "</polygone>".len();
+        assert!((start..end).contains(&err.offset().unwrap()));
+    }
+
+    #[test]
+    fn invalid_value() {
+        let input = INPUT.replacen(
+            "<urgency>Immediate</urgency>",
+            "<urgency>Eventually</urgency>",
+            1,
+        );
+        let err = input.parse::<crate::v1dot2::Alert>().unwrap_err();
+        assert_eq!(err.path(), Some("info[0].urgency"));
+        assert!(input[err.offset().unwrap()..].starts_with("Eventually"));
+        assert!(err.to_string().contains(" at info[0].urgency (byte "));
+    }
+
+    #[test]
+    fn invalid_value_in_later_info() {
+        let input = include_str!("../fixtures/v1dot2_appendix_adot4.xml");
+        let second = input.rfind("<urgency>Immediate</urgency>").unwrap();
+        let input = format!(
+            "{}<urgency>Eventually</urgency>{}",
+            &input[..second],
+            &input[second + "<urgency>Immediate</urgency>".len()..]
+        );
+
+        // The error lists `Immediate` as an alternative, but info[0]'s `Immediate` is valid
+        let err = input.parse::<crate::v1dot2::Alert>().unwrap_err();
+        assert_eq!(err.path(), Some("info[1].urgency"));
+        assert!(input[err.offset().unwrap()..].starts_with("Eventually"));
+    }
+
+    #[test]
+    fn first_quoted() {
+        assert_eq!(
+            super::first_quoted("unknown variant `Foo`, expected `Bar` or `Baz`"),
+            Some("Foo")
+        );
+        assert_eq!(
+            super::first_quoted("invalid value: string \"Foo\", expected `Bar`"),
+            Some("Foo")
+        );
+        assert_eq!(super::first_quoted("no quotes"), None);
+        assert_eq!(super::first_quoted("unterminated `Foo"), None);
+    }
+
+    #[test]
+    fn reported_element() {
+        assert_eq!(
+            super::reported_element(
+                "missing field `{urn:oasis:names:tc:emergency:cap:1.2;}cap:event`"
+            ),
+            Some("event")
+        );
+        assert_eq!(super::reported_element("unknown variant `Foo`"), None);
+    }
+
+    #[test]
+    fn root_element_start() {
+        assert_eq!(super::root_element_start("<alert/>"), None);
+        assert_eq!(
+            super::root_element_start("<?xml version=\"1.0\"?><alert/>"),
+            None
+        );
+        assert_eq!(
+            super::root_element_start("<?xml version=\"1.0\"?>\n<alert/>"),
+            None
+        );
+        assert_eq!(super::root_element_start("\n\n<alert/>"), Some(2));
+        assert_eq!(super::root_element_start("<!-- é --><alert/>"), Some(11));
+        assert_eq!(
+            super::root_element_start(
+                "<?xml version=\"1.0\"?>\n<?xml-stylesheet href=\"a\"?>\n<a/>"
+            ),
+            Some(50)
+        );
+    }
+
+    #[test]
+    fn prefixed_fixtures() {
+        for input in [
+            include_str!("../fixtures/v1dot2_comment_prefixed.xml"),
+            include_str!("../fixtures/v1dot2_stylesheet_prefixed.xml"),
+        ] {
+            let alert: crate::Alert = input.parse().unwrap();
+            assert_eq!(alert.identifier().as_str(), "43b080713727");
+        }
+    }
+
+    #[test]
+    fn unsupported_namespace() {
+        let unsupported = |input: &str| match input.parse::<crate::Alert>() {
+            Err(crate::Error::UnsupportedNamespace(namespace)) => Some(namespace),
+            _ => None,
+        };
+
+        let input = INPUT.replacen("urn:oasis:names:tc:emergency:cap:1.2", "urn:example:cap", 1);
+        assert_eq!(unsupported(&input).as_deref(), Some("urn:example:cap"));
+        assert_eq!(input.parse::<crate::Alert>().unwrap_err().path(), None);
+
+        // Without a namespace, it's still not a supported alert
+        let input = INPUT.replacen(r#" xmlns = "urn:oasis:names:tc:emergency:cap:1.2""#, "", 1);
+        assert_eq!(unsupported(&input).as_deref(), Some(""));
+
+        // The namespace is reported even if the document is truncated after the root element
+        let input = INPUT.replacen("urn:oasis:names:tc:emergency:cap:1.2", "urn:example:cap", 1);
+        let truncated = &input[..input.find("</polygon>").unwrap()];
+        assert_eq!(unsupported(truncated).as_deref(), Some("urn:example:cap"));
+
+        // A document whose root element can't be read is a parse error
+        assert!(matches!(
+            "<alert".parse::<crate::Alert>(),
+            Err(crate::Error::Parse(_))
+        ));
+
+        // Problems within a supported alert are parse errors
+        let input = INPUT.replacen("<urgency>Immediate</urgency>", "<urgency>Soon</urgency>", 1);
+        assert!(matches!(
+            input.parse::<crate::Alert>(),
+            Err(crate::Error::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn byte_offset() {
+        let xml = "<a>\n  <bé/>\n</a>";
+        assert_eq!(super::byte_offset(xml, 0, 0), 0);
+        assert_eq!(super::byte_offset(xml, 1, 2), 6);
+        assert_eq!(super::byte_offset(xml, 1, 5), 10);
+        assert_eq!(super::byte_offset(xml, 2, 0), 13);
+    }
+}
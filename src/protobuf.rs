@@ -14,6 +14,9 @@ mod certainty;
 mod delimited_items;
 mod geo;
 mod info_conversion;
+#[cfg(feature = "protobuf-json")]
+mod json;
+mod lossy;
 mod map;
 mod message_type;
 mod references;
@@ -22,12 +25,17 @@ mod response_type;
 mod scope;
 mod severity;
 mod status;
+mod stream;
 mod urgency;
 
 pub use alert_conversion::AlertConversionError;
 pub use area_conversion::AreaConversionError;
 pub use info_conversion::InfoConversionError;
+#[cfg(feature = "protobuf-json")]
+pub use json::JsonError;
+pub use lossy::ConversionWarning;
 pub use resource_conversion::ResourceConversionError;
+pub use stream::{decode_all, encode_all, DecodeAllError};
 
 impl TryFrom<Alert> for crate::Alert {
     type Error = AlertConversionError;
@@ -52,6 +60,41 @@ impl From<crate::Alert> for Alert {
     }
 }
 
+impl Alert {
+    /// Parse a CAP XML document of any version into a protobuf `Alert`.
+    ///
+    /// This is shorthand for parsing an `oasiscap::Alert` and converting it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot1_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.1 appendix A.1 */;
+    /// let proto = oasiscap::protobuf::Alert::from_cap_xml(input).unwrap();
+    /// assert_eq!(proto.xmlns, "urn:oasis:names:tc:emergency:cap:1.1");
+    ///
+    /// let xml = proto.to_cap_xml().unwrap();
+    /// assert_eq!(
+    ///     xml.parse::<oasiscap::Alert>().unwrap(),
+    ///     input.parse::<oasiscap::Alert>().unwrap(),
+    /// );
+    /// ```
+    pub fn from_cap_xml(xml: &str) -> Result<Self, crate::Error> {
+        xml.parse::<crate::Alert>().map(Self::from)
+    }
+
+    /// Convert this protobuf `Alert` into a CAP XML document, in the CAP version given by `xmlns`.
+    ///
+    /// This is shorthand for converting to an `oasiscap::Alert`, which can fail, and formatting it.
+    /// A failed conversion is reported as `Error::Serialize`, with the `AlertConversionError` as
+    /// its source.
+    pub fn to_cap_xml(&self) -> Result<String, crate::Error> {
+        let alert =
+            crate::Alert::try_from(self.clone()).map_err(|e| crate::Error::Serialize(e.into()))?;
+        crate::parse_error::to_string(&alert)
+    }
+}
+
 fn datetime(optional: Option<String>) -> Result<Option<crate::DateTime>, chrono::ParseError> {
     match optional {
         Some(string) => string.parse().map(Some),
@@ -0,0 +1,542 @@
+//! The canonical Protocol Buffers JSON mapping.
+//!
+//! Fields are named in `lowerCamelCase`, unset optional and empty repeated fields are omitted,
+//! enums are written by name, and 64-bit integers are written as strings. Parsing also accepts
+//! enums as numbers and 64-bit integers as numbers, as the mapping requires.
+
+use super::alert::{MsgType, Scope, Status};
+use super::info::{Category, Certainty, ResponseType, Severity, Urgency};
+use super::{Alert, Area, Circle, Group, Info, Point, Polygon, Resource, ValuePair};
+use serde_json::{Map, Value};
+
+/// The error returned when JSON does not describe a protobuf `Alert`.
+#[derive(thiserror::Error, Debug, Clone, Eq, PartialEq)]
+pub enum JsonError {
+    /// A value was not a JSON object
+    #[error("expected an object")]
+    NotAnObject,
+    /// A required field is missing
+    #[error("field {0:?} is missing")]
+    Missing(&'static str),
+    /// A field has a value of the wrong type, or an unknown enum name
+    #[error("field {0:?} is invalid")]
+    Invalid(&'static str),
+}
+
+impl Alert {
+    /// Represent this `Alert` as JSON, according to the canonical Protocol Buffers JSON mapping.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let input = include_str!("../../fixtures/v1dot2_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.1 */;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    /// let proto = oasiscap::protobuf::Alert::from(alert);
+    ///
+    /// let json = proto.to_json();
+    /// assert_eq!(json["msgType"], "ALERT");
+    /// assert_eq!(json["info"][0]["category"][0], "SECURITY");
+    ///
+    /// let roundtrip = oasiscap::protobuf::Alert::from_json(&json).unwrap();
+    /// assert_eq!(roundtrip, proto);
+    /// ```
+    pub fn to_json(&self) -> Value {
+        let mut w = Writer::default();
+        w.string("xmlns", &self.xmlns);
+        w.string("identifier", &self.identifier);
+        w.string("sender", &self.sender);
+        #[allow(deprecated)]
+        w.opt_string("password", &self.password);
+        w.string("sent", &self.sent);
+        w.enumeration::<Status>("status", self.status);
+        w.enumeration::<MsgType>("msgType", self.msg_type);
+        w.opt_string("source", &self.source);
+        if let Some(scope) = self.scope {
+            w.enumeration::<Scope>("scope", scope);
+        }
+        w.opt_string("restriction", &self.restriction);
+        w.opt_message("addresses", &self.addresses, Group::to_json);
+        w.strings("code", &self.code);
+        w.opt_string("note", &self.note);
+        w.opt_message("references", &self.references, Group::to_json);
+        w.opt_message("incidents", &self.incidents, Group::to_json);
+        w.messages("info", &self.info, Info::to_json);
+        w.finish()
+    }
+
+    /// Parse an `Alert` from JSON, according to the canonical Protocol Buffers JSON mapping.
+    pub fn from_json(value: &Value) -> Result<Self, JsonError> {
+        let r = Reader::new(value)?;
+        #[allow(deprecated)]
+        Ok(Self {
+            xmlns: r.string("xmlns")?,
+            identifier: r.string("identifier")?,
+            sender: r.string("sender")?,
+            password: r.opt_string("password")?,
+            sent: r.string("sent")?,
+            status: r.enumeration::<Status>("status")?,
+            msg_type: r.enumeration::<MsgType>("msgType")?,
+            source: r.opt_string("source")?,
+            scope: r.opt_enumeration::<Scope>("scope")?,
+            restriction: r.opt_string("restriction")?,
+            addresses: r.opt_message("addresses", Group::from_json)?,
+            code: r.strings("code")?,
+            note: r.opt_string("note")?,
+            references: r.opt_message("references", Group::from_json)?,
+            incidents: r.opt_message("incidents", Group::from_json)?,
+            info: r.messages("info", Info::from_json)?,
+        })
+    }
+}
+
+impl Info {
+    fn to_json(&self) -> Value {
+        let mut w = Writer::default();
+        w.opt_string("language", &self.language);
+        w.enumerations::<Category>("category", &self.category);
+        w.string("event", &self.event);
+        w.enumerations::<ResponseType>("responseType", &self.response_type);
+        w.enumeration::<Urgency>("urgency", self.urgency);
+        w.enumeration::<Severity>("severity", self.severity);
+        w.enumeration::<Certainty>("certainty", self.certainty);
+        w.opt_string("audience", &self.audience);
+        w.messages("eventCode", &self.event_code, ValuePair::to_json);
+        w.opt_string("effective", &self.effective);
+        w.opt_string("onset", &self.onset);
+        w.opt_string("expires", &self.expires);
+        w.opt_string("senderName", &self.sender_name);
+        w.opt_string("headline", &self.headline);
+        w.opt_string("description", &self.description);
+        w.opt_string("instruction", &self.instruction);
+        w.opt_string("web", &self.web);
+        w.opt_string("contact", &self.contact);
+        w.messages("parameter", &self.parameter, ValuePair::to_json);
+        w.messages("resource", &self.resource, Resource::to_json);
+        w.messages("area", &self.area, Area::to_json);
+        w.finish()
+    }
+
+    fn from_json(value: &Value) -> Result<Self, JsonError> {
+        let r = Reader::new(value)?;
+        Ok(Self {
+            language: r.opt_string("language")?,
+            category: r.enumerations::<Category>("category")?,
+            event: r.string("event")?,
+            response_type: r.enumerations::<ResponseType>("responseType")?,
+            urgency: r.enumeration::<Urgency>("urgency")?,
+            severity: r.enumeration::<Severity>("severity")?,
+            certainty: r.enumeration::<Certainty>("certainty")?,
+            audience: r.opt_string("audience")?,
+            event_code: r.messages("eventCode", ValuePair::from_json)?,
+            effective: r.opt_string("effective")?,
+            onset: r.opt_string("onset")?,
+            expires: r.opt_string("expires")?,
+            sender_name: r.opt_string("senderName")?,
+            headline: r.opt_string("headline")?,
+            description: r.opt_string("description")?,
+            instruction: r.opt_string("instruction")?,
+            web: r.opt_string("web")?,
+            contact: r.opt_string("contact")?,
+            parameter: r.messages("parameter", ValuePair::from_json)?,
+            resource: r.messages("resource", Resource::from_json)?,
+            area: r.messages("area", Area::from_json)?,
+        })
+    }
+}
+
+impl Resource {
+    fn to_json(&self) -> Value {
+        let mut w = Writer::default();
+        w.string("resourceDesc", &self.resource_desc);
+        w.opt_string("mimeType", &self.mime_type);
+        if let Some(size) = self.size {
+            // 64-bit integers are strings in JSON
+            w.string("size", &size.to_string());
+        }
+        w.opt_string("uri", &self.uri);
+        w.opt_string("derefUri", &self.deref_uri);
+        w.opt_string("digest", &self.digest);
+        w.finish()
+    }
+
+    fn from_json(value: &Value) -> Result<Self, JsonError> {
+        let r = Reader::new(value)?;
+        Ok(Self {
+            resource_desc: r.string("resourceDesc")?,
+            mime_type: r.opt_string("mimeType")?,
+            size: r.opt_i64("size")?,
+            uri: r.opt_string("uri")?,
+            deref_uri: r.opt_string("derefUri")?,
+            digest: r.opt_string("digest")?,
+        })
+    }
+}
+
+impl Area {
+    fn to_json(&self) -> Value {
+        let mut w = Writer::default();
+        w.string("areaDesc", &self.area_desc);
+        w.messages("polygon", &self.polygon, Polygon::to_json);
+        w.messages("circle", &self.circle, Circle::to_json);
+        w.messages("geocode", &self.geocode, ValuePair::to_json);
+        w.opt_f64("altitude", self.altitude);
+        w.opt_f64("ceiling", self.ceiling);
+        w.finish()
+    }
+
+    fn from_json(value: &Value) -> Result<Self, JsonError> {
+        let r = Reader::new(value)?;
+        Ok(Self {
+            area_desc: r.string("areaDesc")?,
+            polygon: r.messages("polygon", Polygon::from_json)?,
+            circle: r.messages("circle", Circle::from_json)?,
+            geocode: r.messages("geocode", ValuePair::from_json)?,
+            altitude: r.opt_f64("altitude")?,
+            ceiling: r.opt_f64("ceiling")?,
+        })
+    }
+}
+
+impl Polygon {
+    fn to_json(&self) -> Value {
+        let mut w = Writer::default();
+        w.messages("point", &self.point, Point::to_json);
+        w.finish()
+    }
+
+    fn from_json(value: &Value) -> Result<Self, JsonError> {
+        let r = Reader::new(value)?;
+        Ok(Self {
+            point: r.messages("point", Point::from_json)?,
+        })
+    }
+}
+
+impl Circle {
+    fn to_json(&self) -> Value {
+        let mut w = Writer::default();
+        w.message("point", &self.point, Point::to_json);
+        w.f64("radius", self.radius);
+        w.finish()
+    }
+
+    fn from_json(value: &Value) -> Result<Self, JsonError> {
+        let r = Reader::new(value)?;
+        Ok(Self {
+            point: r.message("point", Point::from_json)?,
+            radius: r.f64("radius")?,
+        })
+    }
+}
+
+impl Point {
+    fn to_json(&self) -> Value {
+        let mut w = Writer::default();
+        w.f64("latitude", self.latitude);
+        w.f64("longitude", self.longitude);
+        w.finish()
+    }
+
+    fn from_json(value: &Value) -> Result<Self, JsonError> {
+        let r = Reader::new(value)?;
+        Ok(Self {
+            latitude: r.f64("latitude")?,
+            longitude: r.f64("longitude")?,
+        })
+    }
+}
+
+impl Group {
+    fn to_json(&self) -> Value {
+        let mut w = Writer::default();
+        w.strings("value", &self.value);
+        w.finish()
+    }
+
+    fn from_json(value: &Value) -> Result<Self, JsonError> {
+        let r = Reader::new(value)?;
+        Ok(Self {
+            value: r.strings("value")?,
+        })
+    }
+}
+
+impl ValuePair {
+    fn to_json(&self) -> Value {
+        let mut w = Writer::default();
+        w.string("valueName", &self.value_name);
+        w.string("value", &self.value);
+        w.finish()
+    }
+
+    fn from_json(value: &Value) -> Result<Self, JsonError> {
+        let r = Reader::new(value)?;
+        Ok(Self {
+            value_name: r.string("valueName")?,
+            value: r.string("value")?,
+        })
+    }
+}
+
+/// A prost-generated enumeration.
+trait Enumeration: std::fmt::Debug + Sized {
+    fn from_i32(value: i32) -> Option<Self>;
+
+    /// The name of `value` in the `.proto` file, if it is known.
+    ///
+    /// prost converts `SHOUTY_SNAKE_CASE` names to `UpperCamelCase`, so this converts them back.
+    fn name(value: i32) -> Option<String> {
+        let camel = format!("{:?}", Self::from_i32(value)?);
+        let mut name = String::with_capacity(camel.len() + 4);
+        for (i, c) in camel.chars().enumerate() {
+            if i > 0 && c.is_ascii_uppercase() {
+                name.push('_');
+            }
+            name.push(c.to_ascii_uppercase());
+        }
+        Some(name)
+    }
+
+    /// The value with a given name, if any.
+    fn value(name: &str) -> Option<i32> {
+        // Every CAP enumeration is numbered contiguously from zero
+        (0..)
+            .map_while(|value| Self::name(value).map(|n| (value, n)))
+            .find(|(_, n)| n == name)
+            .map(|(value, _)| value)
+    }
+}
+
+macro_rules! impl_enumeration {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl Enumeration for $t {
+                fn from_i32(value: i32) -> Option<Self> {
+                    <$t>::from_i32(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_enumeration!(
+    Status,
+    MsgType,
+    Scope,
+    Category,
+    ResponseType,
+    Urgency,
+    Severity,
+    Certainty
+);
+
+#[derive(Default)]
+struct Writer(Map<String, Value>);
+
+impl Writer {
+    fn finish(self) -> Value {
+        Value::Object(self.0)
+    }
+
+    fn string(&mut self, name: &str, value: &str) {
+        self.0.insert(name.into(), value.into());
+    }
+
+    fn opt_string(&mut self, name: &str, value: &Option<String>) {
+        if let Some(value) = value {
+            self.string(name, value);
+        }
+    }
+
+    fn strings(&mut self, name: &str, values: &[String]) {
+        if !values.is_empty() {
+            self.0.insert(name.into(), values.into());
+        }
+    }
+
+    fn f64(&mut self, name: &str, value: f64) {
+        self.0.insert(name.into(), value.into());
+    }
+
+    fn opt_f64(&mut self, name: &str, value: Option<f64>) {
+        if let Some(value) = value {
+            self.f64(name, value);
+        }
+    }
+
+    fn enumeration<E: Enumeration>(&mut self, name: &str, value: i32) {
+        // Unknown values are written as numbers
+        let value = E::name(value).map_or(value.into(), Value::from);
+        self.0.insert(name.into(), value);
+    }
+
+    fn enumerations<E: Enumeration>(&mut self, name: &str, values: &[i32]) {
+        if !values.is_empty() {
+            let values = values
+                .iter()
+                .map(|&value| E::name(value).map_or(value.into(), Value::from))
+                .collect();
+            self.0.insert(name.into(), Value::Array(values));
+        }
+    }
+
+    fn message<T>(&mut self, name: &str, value: &T, to_json: fn(&T) -> Value) {
+        self.0.insert(name.into(), to_json(value));
+    }
+
+    fn opt_message<T>(&mut self, name: &str, value: &Option<T>, to_json: fn(&T) -> Value) {
+        if let Some(value) = value {
+            self.message(name, value, to_json);
+        }
+    }
+
+    fn messages<T>(&mut self, name: &str, values: &[T], to_json: fn(&T) -> Value) {
+        if !values.is_empty() {
+            self.0
+                .insert(name.into(), values.iter().map(to_json).collect());
+        }
+    }
+}
+
+struct Reader<'a>(&'a Map<String, Value>);
+
+impl<'a> Reader<'a> {
+    fn new(value: &'a Value) -> Result<Self, JsonError> {
+        value.as_object().map(Self).ok_or(JsonError::NotAnObject)
+    }
+
+    /// The value of a field, treating `null` as absent.
+    fn get(&self, name: &'static str) -> Option<&'a Value> {
+        self.0.get(name).filter(|value| !value.is_null())
+    }
+
+    /// The elements of a repeated field.
+    fn array(&self, name: &'static str) -> Result<&'a [Value], JsonError> {
+        match self.get(name) {
+            Some(value) => value
+                .as_array()
+                .map(Vec::as_slice)
+                .ok_or(JsonError::Invalid(name)),
+            None => Ok(&[]),
+        }
+    }
+
+    fn string(&self, name: &'static str) -> Result<String, JsonError> {
+        self.opt_string(name)?.ok_or(JsonError::Missing(name))
+    }
+
+    fn opt_string(&self, name: &'static str) -> Result<Option<String>, JsonError> {
+        self.get(name)
+            .map(|value| {
+                value
+                    .as_str()
+                    .map(String::from)
+                    .ok_or(JsonError::Invalid(name))
+            })
+            .transpose()
+    }
+
+    fn strings(&self, name: &'static str) -> Result<Vec<String>, JsonError> {
+        self.array(name)?
+            .iter()
+            .map(|value| {
+                value
+                    .as_str()
+                    .map(String::from)
+                    .ok_or(JsonError::Invalid(name))
+            })
+            .collect()
+    }
+
+    fn f64(&self, name: &'static str) -> Result<f64, JsonError> {
+        self.opt_f64(name)?.ok_or(JsonError::Missing(name))
+    }
+
+    fn opt_f64(&self, name: &'static str) -> Result<Option<f64>, JsonError> {
+        self.get(name)
+            .map(|value| value.as_f64().ok_or(JsonError::Invalid(name)))
+            .transpose()
+    }
+
+    fn opt_i64(&self, name: &'static str) -> Result<Option<i64>, JsonError> {
+        self.get(name)
+            .map(|value| match value {
+                Value::String(s) => s.parse().map_err(|_| JsonError::Invalid(name)),
+                value => value.as_i64().ok_or(JsonError::Invalid(name)),
+            })
+            .transpose()
+    }
+
+    fn enumeration<E: Enumeration>(&self, name: &'static str) -> Result<i32, JsonError> {
+        self.opt_enumeration::<E>(name)?
+            .ok_or(JsonError::Missing(name))
+    }
+
+    fn opt_enumeration<E: Enumeration>(
+        &self,
+        name: &'static str,
+    ) -> Result<Option<i32>, JsonError> {
+        self.get(name)
+            .map(|value| parse_enumeration::<E>(name, value))
+            .transpose()
+    }
+
+    fn enumerations<E: Enumeration>(&self, name: &'static str) -> Result<Vec<i32>, JsonError> {
+        self.array(name)?
+            .iter()
+            .map(|value| parse_enumeration::<E>(name, value))
+            .collect()
+    }
+
+    fn message<T>(
+        &self,
+        name: &'static str,
+        from_json: fn(&Value) -> Result<T, JsonError>,
+    ) -> Result<T, JsonError> {
+        self.opt_message(name, from_json)?
+            .ok_or(JsonError::Missing(name))
+    }
+
+    fn opt_message<T>(
+        &self,
+        name: &'static str,
+        from_json: fn(&Value) -> Result<T, JsonError>,
+    ) -> Result<Option<T>, JsonError> {
+        self.get(name).map(from_json).transpose()
+    }
+
+    fn messages<T>(
+        &self,
+        name: &'static str,
+        from_json: fn(&Value) -> Result<T, JsonError>,
+    ) -> Result<Vec<T>, JsonError> {
+        self.array(name)?.iter().map(from_json).collect()
+    }
+}
+
+fn parse_enumeration<E: Enumeration>(name: &'static str, value: &Value) -> Result<i32, JsonError> {
+    let value = match value {
+        Value::String(s) => E::value(s),
+        value => value.as_i64().and_then(|v| i32::try_from(v).ok()),
+    };
+    value.ok_or(JsonError::Invalid(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enumeration_names() {
+        assert_eq!(Status::name(0).as_deref(), Some("ACTUAL"));
+        assert_eq!(Category::name(10).as_deref(), Some("CBRNE"));
+        assert_eq!(ResponseType::name(7).as_deref(), Some("ALL_CLEAR"));
+        assert_eq!(Certainty::name(1).as_deref(), Some("VERY_LIKELY"));
+        assert_eq!(Urgency::name(4).as_deref(), Some("UNKNOWN_URGENCY"));
+        assert_eq!(Urgency::name(5), None);
+
+        assert_eq!(ResponseType::value("ALL_CLEAR"), Some(7));
+        assert_eq!(ResponseType::value("AllClear"), None);
+    }
+}
@@ -0,0 +1,88 @@
+use super::info::{Certainty, Severity, Urgency};
+use super::{Alert, AlertConversionError};
+
+/// A substitution made by `Alert::try_into_lossy()`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ConversionWarning {
+    /// The path to the substituted field, e.g. `info.0.urgency`
+    pub field: String,
+    /// The unknown value which was replaced
+    pub value: i32,
+}
+
+impl std::fmt::Display for ConversionWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} has unknown value {}, substituted Unknown",
+            self.field, self.value
+        )
+    }
+}
+
+impl Alert {
+    /// Convert this protobuf `Alert` into an `oasiscap::Alert`, substituting `Unknown` for
+    /// unrecognized urgency, severity, and certainty values instead of failing.
+    ///
+    /// Each substitution is reported as a `ConversionWarning`. Unrecognized values in fields which
+    /// have no `Unknown` variant, like `status`, still cause the conversion to fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let input = include_str!("../../fixtures/v1dot2_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.1 */;
+    /// let alert: oasiscap::Alert = input.parse().unwrap();
+    /// let mut proto = oasiscap::protobuf::Alert::from(alert);
+    /// proto.info[0].urgency = 42;
+    ///
+    /// assert!(oasiscap::Alert::try_from(proto.clone()).is_err());
+    ///
+    /// let (alert, warnings) = proto.try_into_lossy().unwrap();
+    /// assert_eq!(alert.into_latest().info[0].urgency, oasiscap::v1dot2::Urgency::Unknown);
+    /// assert_eq!(warnings.len(), 1);
+    /// assert_eq!(warnings[0].field, "info.0.urgency");
+    /// assert_eq!(warnings[0].value, 42);
+    /// ```
+    pub fn try_into_lossy(
+        mut self,
+    ) -> Result<(crate::Alert, Vec<ConversionWarning>), AlertConversionError> {
+        let mut warnings = Vec::new();
+        let mut substitute =
+            |value: &mut i32, is_known: fn(i32) -> bool, unknown: i32, i: usize, name: &str| {
+                if !is_known(*value) {
+                    warnings.push(ConversionWarning {
+                        field: format!("info.{}.{}", i, name),
+                        value: *value,
+                    });
+                    *value = unknown;
+                }
+            };
+
+        for (i, info) in self.info.iter_mut().enumerate() {
+            substitute(
+                &mut info.urgency,
+                |value| Urgency::from_i32(value).is_some(),
+                Urgency::UnknownUrgency as i32,
+                i,
+                "urgency",
+            );
+            substitute(
+                &mut info.severity,
+                |value| Severity::from_i32(value).is_some(),
+                Severity::UnknownSeverity as i32,
+                i,
+                "severity",
+            );
+            substitute(
+                &mut info.certainty,
+                |value| Certainty::from_i32(value).is_some(),
+                Certainty::UnknownCertainty as i32,
+                i,
+                "certainty",
+            );
+        }
+
+        crate::Alert::try_from(self).map(|alert| (alert, warnings))
+    }
+}
@@ -0,0 +1,119 @@
+use super::Alert;
+use ::prost::bytes::{Buf, BufMut};
+use ::prost::{DecodeError, EncodeError, Message};
+
+impl Alert {
+    /// Decode a sequence of length-delimited `Alert`s from `buf`, until it is exhausted.
+    ///
+    /// Iteration stops after the first error, since the remainder of the buffer can no longer be
+    /// framed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let alerts = [
+    /// #     include_str!("../../fixtures/v1dot0_appendix_adot1.xml"),
+    /// #     include_str!("../../fixtures/v1dot2_appendix_adot1.xml"),
+    /// # ].map(|xml| oasiscap::protobuf::Alert::from(xml.parse::<oasiscap::Alert>().unwrap()));
+    /// // let alerts: [oasiscap::protobuf::Alert; 2] = /* … */;
+    /// let mut buf = Vec::new();
+    /// oasiscap::protobuf::Alert::encode_stream(&alerts, &mut buf).unwrap();
+    ///
+    /// let decoded = oasiscap::protobuf::Alert::decode_stream(buf.as_slice())
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    /// assert_eq!(decoded, alerts);
+    ///
+    /// // Truncated input produces an error
+    /// let mut truncated = oasiscap::protobuf::Alert::decode_stream(&buf[..buf.len() - 1]);
+    /// assert!(truncated.next().unwrap().is_ok());
+    /// assert!(truncated.next().unwrap().is_err());
+    /// assert!(truncated.next().is_none());
+    /// ```
+    pub fn decode_stream<B: Buf>(mut buf: B) -> impl Iterator<Item = Result<Alert, DecodeError>> {
+        let mut failed = false;
+        std::iter::from_fn(move || {
+            if failed || !buf.has_remaining() {
+                return None;
+            }
+            let result = Alert::decode_length_delimited(&mut buf);
+            failed = result.is_err();
+            Some(result)
+        })
+    }
+
+    /// Encode a sequence of `Alert`s into `buf`, each prefixed by its length.
+    ///
+    /// The result can be read back with `Alert::decode_stream()`.
+    pub fn encode_stream<'a, I, B>(alerts: I, buf: &mut B) -> Result<(), EncodeError>
+    where
+        I: IntoIterator<Item = &'a Alert>,
+        B: BufMut,
+    {
+        alerts
+            .into_iter()
+            .try_for_each(|alert| alert.encode_length_delimited(buf))
+    }
+}
+
+/// Encode `alerts` as a sequence of length-delimited protobuf `Alert`s.
+///
+/// The result can be read back with `decode_all()`, or with `Alert::decode_stream()`.
+///
+/// # Example
+///
+/// ```
+/// # let alerts = [
+/// #     include_str!("../../fixtures/v1dot0_appendix_adot1.xml"),
+/// #     include_str!("../../fixtures/v1dot1_appendix_adot1.xml"),
+/// #     include_str!("../../fixtures/v1dot2_appendix_adot1.xml"),
+/// # ].map(|xml| xml.parse::<oasiscap::Alert>().unwrap());
+/// // let alerts: [oasiscap::Alert; 3] = /* … */;
+/// let bytes = oasiscap::protobuf::encode_all(&alerts);
+/// assert_eq!(oasiscap::protobuf::decode_all(&bytes).unwrap(), alerts);
+///
+/// assert!(oasiscap::protobuf::decode_all(&bytes[..bytes.len() - 1]).is_err());
+/// ```
+pub fn encode_all(alerts: &[crate::Alert]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    for alert in alerts {
+        Alert::from(alert.clone())
+            .encode_length_delimited(&mut buf)
+            .expect("a Vec has unlimited capacity");
+    }
+    buf
+}
+
+/// Decode a sequence of length-delimited protobuf `Alert`s, as produced by `encode_all()`, and
+/// convert each into an `oasiscap::Alert`.
+pub fn decode_all(buf: &[u8]) -> Result<Vec<crate::Alert>, DecodeAllError> {
+    Alert::decode_stream(buf)
+        .enumerate()
+        .map(|(index, result)| {
+            let alert = result.map_err(|source| DecodeAllError::Decode { index, source })?;
+            crate::Alert::try_from(alert)
+                .map_err(|source| DecodeAllError::Conversion { index, source })
+        })
+        .collect()
+}
+
+/// The error returned by `decode_all()`.
+#[derive(thiserror::Error, Debug)]
+pub enum DecodeAllError {
+    /// The alert at `index` could not be decoded. No further alerts can be read.
+    #[error("alert {index} could not be decoded: {source}")]
+    Decode {
+        /// The position of the alert within the sequence
+        index: usize,
+        /// The underlying error
+        source: DecodeError,
+    },
+    /// The alert at `index` was decoded, but could not be converted into an `oasiscap::Alert`.
+    #[error("alert {index} could not be converted: {source}")]
+    Conversion {
+        /// The position of the alert within the sequence
+        index: usize,
+        /// The underlying error
+        source: super::AlertConversionError,
+    },
+}
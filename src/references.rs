@@ -43,6 +43,30 @@ impl References {
         Self(references)
     }
 
+    /// Instantiate `References` by cloning a slice of `Reference`s.
+    ///
+    /// `References` can also be parsed from a slice of strings, one per reference:
+    ///
+    /// ```
+    /// use oasiscap::references::References;
+    ///
+    /// let references = References::try_from(
+    ///     &[
+    ///         "hsas@dhs.gov,43b080713727,2003-04-02T14:39:01-05:00",
+    ///         "hsas@dhs.gov,43b080713728,2003-04-02T15:39:01-05:00",
+    ///     ][..],
+    /// )
+    /// .unwrap();
+    /// assert_eq!(references.len(), 2);
+    ///
+    /// assert_eq!(References::from_slice(&references[1..]).len(), 1);
+    ///
+    /// assert!(References::try_from(&["hsas@dhs.gov,43b080713727"][..]).is_err());
+    /// ```
+    pub fn from_slice(references: &[Reference]) -> Self {
+        Self(references.to_vec())
+    }
+
     /// Move out of `References` into a `Vec<Reference>`.
     pub fn into_inner(self) -> Vec<Reference> {
         self.0
@@ -58,6 +82,45 @@ impl References {
     pub fn iter(&self) -> std::slice::Iter<Reference> {
         self.0.iter()
     }
+
+    /// Look up each referenced alert in `store`, returning `None` for those it does not contain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use oasiscap::references::Reference;
+    ///
+    /// let chain = oasiscap::v1dot2::Alert::chain_example();
+    ///
+    /// // Store the initial alert, but not the update
+    /// let initial = oasiscap::Alert::from(chain[0].clone());
+    /// let mut store = HashMap::new();
+    /// store.insert(Reference::from(&initial), initial.clone());
+    ///
+    /// // Resolve the cancellation's references
+    /// let references = chain[2].references.as_ref().unwrap();
+    /// assert_eq!(references.resolve(&store), vec![Some(initial), None]);
+    /// ```
+    pub fn resolve<S: AlertStore>(&self, store: &S) -> Vec<Option<crate::Alert>> {
+        self.iter().map(|reference| store.get(reference)).collect()
+    }
+}
+
+/// A collection of alerts which can be looked up by `Reference`.
+///
+/// This is implemented for `HashMap<Reference, Alert>`.
+pub trait AlertStore {
+    /// Return the alert identified by `reference`, if present.
+    fn get(&self, reference: &Reference) -> Option<crate::Alert>;
+}
+
+impl<S: std::hash::BuildHasher> AlertStore
+    for std::collections::HashMap<Reference, crate::Alert, S>
+{
+    fn get(&self, reference: &Reference) -> Option<crate::Alert> {
+        std::collections::HashMap::get(self, reference).cloned()
+    }
 }
 
 impl Deref for References {
@@ -80,6 +143,14 @@ impl From<References> for Vec<Reference> {
     }
 }
 
+impl TryFrom<&[&str]> for References {
+    type Error = ReferenceError;
+
+    fn try_from(value: &[&str]) -> Result<Self, Self::Error> {
+        value.iter().map(|reference| reference.parse()).collect()
+    }
+}
+
 impl FromIterator<Reference> for References {
     fn from_iter<T: IntoIterator<Item = Reference>>(iter: T) -> Self {
         Self(iter.into_iter().collect())
@@ -105,7 +176,68 @@ impl IntoIterator for References {
 }
 
 /// An alert reference
-#[derive(Debug, Clone, Eq, PartialEq)]
+///
+/// CAP writes each reference as `sender,identifier,sent`, with exactly three comma-separated
+/// fields and no whitespace, and separates multiple references with whitespace. Neither `sender`
+/// nor `identifier` may contain commas or whitespace, so this encoding is unambiguous. Anything
+/// else is rejected with `ReferenceError::Format` rather than guessing where one field ends:
+///
+/// ```
+/// use oasiscap::references::{Reference, ReferenceError};
+///
+/// let reference: Reference = "hsas@dhs.gov,123,2003-04-02T14:39:01-05:00".parse().unwrap();
+/// assert_eq!(reference.identifier, "123");
+/// assert_eq!(reference.to_string(), "hsas@dhs.gov,123,2003-04-02T14:39:01-05:00");
+///
+/// // An identifier containing a comma would produce four fields
+/// assert!(matches!(
+///     "hsas@dhs.gov,12,3,2003-04-02T14:39:01-05:00".parse::<Reference>(),
+///     Err(ReferenceError::Format(_))
+/// ));
+///
+/// // An omitted identifier would leave the timestamp in its place
+/// assert!(matches!(
+///     "hsas@dhs.gov,2003-04-02T14:39:01-05:00".parse::<Reference>(),
+///     Err(ReferenceError::Format(_))
+/// ));
+///
+/// // An identifier which looks like a timestamp is still an identifier
+/// let reference: Reference =
+///     "hsas@dhs.gov,2003-04-02T14:39:01-05:00,2003-04-02T14:39:01-05:00".parse().unwrap();
+/// assert_eq!(reference.identifier, "2003-04-02T14:39:01-05:00");
+///
+/// // Whitespace separates references, so it cannot appear within one
+/// assert!(matches!(
+///     "hsas@dhs.gov, 123,2003-04-02T14:39:01-05:00".parse::<Reference>(),
+///     Err(ReferenceError::Identifier(_))
+/// ));
+///
+/// // Each field is checked in turn
+/// assert!(matches!(
+///     ",123,2003-04-02T14:39:01-05:00".parse::<Reference>(),
+///     Err(ReferenceError::Sender(_))
+/// ));
+/// assert!(matches!(
+///     "hsas@dhs.gov,123,yesterday".parse::<Reference>(),
+///     Err(ReferenceError::Sent(_))
+/// ));
+/// ```
+///
+/// Whitespace surrounding a single reference is ignored, so references received one per line can
+/// be parsed individually:
+///
+/// ```
+/// use oasiscap::references::Reference;
+///
+/// let input = "hsas@dhs.gov,123,2003-04-02T14:39:01-05:00\r\nhsas@dhs.gov,124,2003-04-02T15:39:01-05:00\r\n";
+/// let references = input
+///     .split_inclusive('\n')
+///     .map(str::parse)
+///     .collect::<Result<Vec<Reference>, _>>()
+///     .unwrap();
+/// assert_eq!(references[1].identifier, "124");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Reference {
     /// The `sender` field of the referenced `Alert`.
     pub sender: Id,
@@ -115,6 +247,16 @@ pub struct Reference {
     pub sent: DateTime,
 }
 
+impl From<&crate::Alert> for Reference {
+    fn from(alert: &crate::Alert) -> Self {
+        Self {
+            sender: alert.sender().clone(),
+            identifier: alert.identifier().clone(),
+            sent: alert.sent(),
+        }
+    }
+}
+
 impl std::fmt::Display for Reference {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{},{},{}", self.sender, self.identifier, self.sent)
@@ -124,8 +266,8 @@ impl std::fmt::Display for Reference {
 /// The error returned when a `Reference` would be invalid.
 #[derive(thiserror::Error, Debug)]
 pub enum ReferenceError {
-    /// Invalid format
-    #[error("invalid format: {0:?}")]
+    /// Invalid format, i.e. not exactly three comma-separated fields
+    #[error("invalid format: expected sender,identifier,sent but got {0:?}")]
     Format(String),
 
     /// Invalid sender
@@ -145,6 +287,7 @@ impl std::str::FromStr for Reference {
     type Err = ReferenceError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
         match {
             let mut i = s.split(',');
             (
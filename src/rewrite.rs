@@ -0,0 +1,248 @@
+//! Rewriting XML produced by `xml_serde`.
+
+use std::borrow::Cow;
+use xml::attribute::Attribute;
+use xml::name::Name;
+use xml::namespace::NS_NO_PREFIX;
+use xml::reader::{EventReader, XmlEvent};
+use xml::writer::{self, EmitterConfig, EventWriter};
+
+/// The prefix `xml_serde` uses for CAP elements, as specified in each type's serde renames.
+const CAP_PREFIX: &str = "cap";
+
+/// Options controlling how an alert is written as XML.
+///
+/// The default options produce the same output as `to_string()`.
+///
+/// # Example
+///
+/// ```
+/// # let input = include_str!("../fixtures/v1dot2_appendix_adot1.xml");
+/// // let input: &str = /* CAP v1.2 appendix A.1 */;
+/// let alert: oasiscap::Alert = input.parse().unwrap();
+///
+/// let xml = alert
+///     .to_string_with(oasiscap::XmlOutputOptions {
+///         default_namespace: true,
+///         ..Default::default()
+///     })
+///     .unwrap();
+/// let root = &xml[xml.find("<alert ").unwrap()..];
+/// let root = &root[..root.find('>').unwrap()];
+/// assert!(root.contains(r#"xmlns="urn:oasis:names:tc:emergency:cap:1.2""#));
+/// assert!(xml.contains("<identifier>") && !xml.contains("<cap:"));
+/// assert_eq!(xml.parse::<oasiscap::Alert>().unwrap(), alert);
+///
+/// assert_eq!(alert.to_string_with(Default::default()).unwrap(), alert.to_string());
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct XmlOutputOptions {
+    /// Declare the CAP namespace as the default namespace and write CAP elements without a prefix,
+    /// e.g. `<alert xmlns="…"><identifier>`, instead of `<cap:alert xmlns:cap="…"><cap:identifier>`.
+    ///
+    /// Both forms are equivalent according to XML namespaces, but not every CAP consumer treats
+    /// them that way.
+    pub default_namespace: bool,
+
+    /// Put each element on its own line, indented by two spaces, as `to_string_pretty()` does.
+    pub indent: bool,
+}
+
+/// Serialize `alert` as XML, indenting each element by two spaces per level.
+///
+/// Whitespace between elements is replaced by the indentation, while text content, including text
+/// which is only whitespace, is passed through untouched.
+pub(crate) fn to_string_pretty<T: serde::Serialize>(alert: &T) -> Result<String, crate::Error> {
+    to_string_with(
+        alert,
+        XmlOutputOptions {
+            indent: true,
+            ..Default::default()
+        },
+    )
+}
+
+/// Serialize `alert` as XML according to `options`.
+pub(crate) fn to_string_with<T: serde::Serialize>(
+    alert: &T,
+    options: XmlOutputOptions,
+) -> Result<String, crate::Error> {
+    rewrite(&crate::parse_error::to_string(alert)?, options)
+}
+
+/// Serialize `alert` as XML and write it to `w`.
+///
+/// `xml_serde` can only serialize to a `String`, so the document is still assembled in memory
+/// before being written.
+pub(crate) fn write_xml<T: serde::Serialize, W: std::io::Write>(
+    alert: &T,
+    mut w: W,
+) -> Result<(), crate::Error> {
+    let xml = crate::parse_error::to_string(alert)?;
+    w.write_all(xml.as_bytes())
+        .map_err(|e| crate::Error::Serialize(e.into()))
+}
+
+/// Re-emit a well-formed XML document produced by `xml_serde` according to `options`.
+fn rewrite(xml: &str, options: XmlOutputOptions) -> Result<String, crate::Error> {
+    if options == XmlOutputOptions::default() {
+        return Ok(xml.to_string());
+    }
+
+    let mut writer = EventWriter::new_with_config(
+        Vec::new(),
+        EmitterConfig::new()
+            .perform_indent(options.indent)
+            .indent_string("  ")
+            .normalize_empty_elements(false),
+    );
+
+    // The default namespace in effect for each open element, which is only tracked when
+    // `options.default_namespace` is set. `EventWriter` never emits `xmlns=""`, so default
+    // namespace declarations are written as attributes here instead.
+    let mut defaults = vec![String::new()];
+
+    // Whitespace-only text is held back until the next event shows whether it's the content of an
+    // element, which is kept, or the space between elements, which indentation replaces.
+    let mut whitespace = String::new();
+    let mut in_leaf = false;
+
+    for event in EventReader::new(xml.as_bytes()) {
+        let event = event.map_err(|e| crate::Error::Serialize(e.into()))?;
+        if let XmlEvent::Whitespace(text) = &event {
+            if options.indent {
+                whitespace.push_str(text);
+                continue;
+            }
+        }
+
+        // Writing the text of a leaf element, even when empty, also keeps the writer from
+        // indenting its end tag onto a new line
+        let write_whitespace = match &event {
+            XmlEvent::Characters(_) | XmlEvent::CData(_) => !whitespace.is_empty(),
+            XmlEvent::EndElement { .. } => in_leaf,
+            _ => false,
+        };
+        if write_whitespace {
+            writer
+                .write(writer::XmlEvent::characters(&whitespace))
+                .map_err(|e| crate::Error::Serialize(e.into()))?;
+        }
+        whitespace.clear();
+        match &event {
+            XmlEvent::StartElement { .. } => in_leaf = true,
+            XmlEvent::EndElement { .. } => in_leaf = false,
+            _ => {}
+        }
+
+        let writer_event = match &event {
+            XmlEvent::StartElement {
+                name,
+                attributes,
+                namespace,
+            } if options.default_namespace => {
+                let mut name = name.borrow();
+                let parent_default = defaults.last().map(String::as_str).unwrap_or_default();
+                let default = match name.prefix {
+                    Some(CAP_PREFIX) => {
+                        name.prefix = None;
+                        name.namespace.unwrap_or_default()
+                    }
+                    Some(_) => parent_default,
+                    None => name.namespace.unwrap_or_default(),
+                }
+                .to_string();
+
+                let mut attributes: Vec<Attribute> = attributes
+                    .iter()
+                    .map(|attribute| attribute.borrow())
+                    .collect();
+                if default != parent_default {
+                    attributes.insert(0, Attribute::new(Name::local("xmlns"), &default));
+                }
+
+                // Other prefixes are still declared by the writer
+                let mut namespace = namespace.clone();
+                namespace.0.remove(CAP_PREFIX);
+                namespace.0.remove(NS_NO_PREFIX);
+
+                let event = writer::XmlEvent::StartElement {
+                    name,
+                    attributes: Cow::Owned(attributes),
+                    namespace: Cow::Owned(namespace),
+                };
+                writer
+                    .write(event)
+                    .map_err(|e| crate::Error::Serialize(e.into()))?;
+                defaults.push(default);
+                continue;
+            }
+            XmlEvent::EndElement { name } if options.default_namespace => {
+                defaults.pop();
+                let mut name = name.borrow();
+                if name.prefix == Some(CAP_PREFIX) {
+                    name.prefix = None;
+                }
+                Some(writer::XmlEvent::EndElement { name: Some(name) })
+            }
+            event => event.as_writer_event(),
+        };
+
+        if let Some(writer_event) = writer_event {
+            writer
+                .write(writer_event)
+                .map_err(|e| crate::Error::Serialize(e.into()))?;
+        }
+    }
+
+    String::from_utf8(writer.into_inner()).map_err(|e| crate::Error::Serialize(e.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indent_preserves_whitespace_content() {
+        let xml = concat!(
+            r#"<?xml version="1.0" encoding="utf-8"?>"#,
+            r#"<cap:alert xmlns:cap="urn:x"><cap:a>  </cap:a>"#,
+            r#"<cap:b><cap:c> x </cap:c><cap:d></cap:d></cap:b></cap:alert>"#,
+        );
+        let options = XmlOutputOptions {
+            indent: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            rewrite(xml, options).unwrap(),
+            concat!(
+                "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n",
+                "<cap:alert xmlns:cap=\"urn:x\">\n",
+                "  <cap:a>  </cap:a>\n",
+                "  <cap:b>\n",
+                "    <cap:c> x </cap:c>\n",
+                "    <cap:d></cap:d>\n",
+                "  </cap:b>\n",
+                "</cap:alert>",
+            )
+        );
+
+        // Re-indenting the output changes nothing
+        let indented = rewrite(xml, options).unwrap();
+        assert_eq!(rewrite(&indented, options).unwrap(), indented);
+    }
+
+    #[test]
+    fn malformed() {
+        let options = XmlOutputOptions {
+            indent: true,
+            ..Default::default()
+        };
+        let error = rewrite("<a><b></a>", options).unwrap_err();
+        assert!(matches!(error, crate::Error::Serialize(_)));
+
+        // The underlying XML error is available as the source
+        let source = std::error::Error::source(&error).unwrap();
+        assert!(source.is::<xml::reader::Error>());
+    }
+}
@@ -0,0 +1,298 @@
+//! Reading a sequence of alerts from a stream.
+
+use crate::subtree;
+use std::collections::VecDeque;
+use std::io::{BufReader, Read};
+use xml::reader::{EventReader, ParserConfig, XmlEvent};
+
+/// An iterator over the alerts in a stream, as returned by `Alert::iter_from_reader()`.
+pub(crate) struct Alerts<R: Read> {
+    reader: Option<EventReader<StripXmlDeclarations<BufReader<R>>>>,
+}
+
+impl<R: Read> Alerts<R> {
+    pub(crate) fn new(read: R) -> Self {
+        let reader = ParserConfig::new()
+            .allow_multiple_root_elements(true)
+            .create_reader(StripXmlDeclarations::new(BufReader::new(read)));
+        Self {
+            reader: Some(reader),
+        }
+    }
+}
+
+impl<R: Read> Iterator for Alerts<R> {
+    type Item = Result<crate::Alert, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let reader = self.reader.as_mut()?;
+        loop {
+            let event = match reader.next() {
+                Ok(XmlEvent::EndDocument) => break,
+                Ok(event) => event,
+                Err(e) => {
+                    // The stream cannot be resumed after an XML error
+                    self.reader = None;
+                    return Some(Err(e.into()));
+                }
+            };
+
+            let is_alert = matches!(
+                &event,
+                XmlEvent::StartElement { name, .. } if subtree::is_alert(name)
+            );
+            if is_alert {
+                return Some(match subtree::read_element(reader, event) {
+                    Ok(xml) => xml.parse().map_err(StreamError::Alert),
+                    Err(e) => {
+                        self.reader = None;
+                        Err(e.into())
+                    }
+                });
+            }
+
+            // Anything else is either a wrapper element, or something to be ignored
+        }
+
+        self.reader = None;
+        None
+    }
+}
+
+/// The error returned when reading alerts from a stream.
+#[derive(thiserror::Error, Debug)]
+pub enum StreamError {
+    /// The stream is not well-formed XML. No further alerts can be read.
+    #[error("invalid XML: {0}")]
+    Xml(#[from] xml::reader::Error),
+    /// An `<alert>` element could not be parsed. Subsequent alerts may still be read.
+    ///
+    /// The error's location is relative to the start of the `<alert>` element.
+    #[error("invalid alert: {0}")]
+    Alert(crate::Error),
+}
+
+/// Blanks out `<?xml …?>` declarations, so that concatenated documents can be read as one.
+///
+/// A declaration at the very start of the stream is left intact. Later declarations are replaced
+/// with spaces (preserving newlines) rather than removed, so that positions reported by the parser
+/// remain accurate. Comments and CDATA sections are passed through untouched, even if they contain
+/// something which looks like a declaration.
+struct StripXmlDeclarations<R> {
+    inner: R,
+    output: VecDeque<u8>,
+    pending: Vec<u8>,
+    offset: u64,
+    state: State,
+    // The two bytes most recently processed in the current state
+    recent: [u8; 2],
+    eof: bool,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum State {
+    Text,
+    // `blank` is set unless this declaration is at the start of the stream
+    Declaration { blank: bool },
+    Comment,
+    CData,
+}
+
+impl<R: Read> StripXmlDeclarations<R> {
+    const DECLARATION: &'static [u8] = b"<?xml";
+    const COMMENT: &'static [u8] = b"<!--";
+    const CDATA: &'static [u8] = b"<![CDATA[";
+
+    fn new(inner: R) -> Self {
+        Self {
+            inner,
+            output: VecDeque::new(),
+            pending: Vec::new(),
+            offset: 0,
+            state: State::Text,
+            recent: [0; 2],
+            eof: false,
+        }
+    }
+
+    fn emit(&mut self, byte: u8, blank: bool) {
+        self.output.push_back(match byte {
+            b'\n' => b'\n',
+            _ if blank => b' ',
+            _ => byte,
+        });
+    }
+
+    fn enter(&mut self, state: State) {
+        self.state = state;
+        self.recent = [0; 2];
+    }
+
+    fn process(&mut self, byte: u8) {
+        self.offset += 1;
+        let recent = self.recent;
+        self.recent = [recent[1], byte];
+
+        let end: Option<&[u8]> = match self.state {
+            State::Text => None,
+            State::Declaration { blank } => {
+                self.emit(byte, blank);
+                Some(b"?")
+            }
+            State::Comment => {
+                self.output.push_back(byte);
+                Some(b"--")
+            }
+            State::CData => {
+                self.output.push_back(byte);
+                Some(b"]]")
+            }
+        };
+        if let Some(end) = end {
+            if byte == b'>' && recent.ends_with(end) {
+                self.enter(State::Text);
+            }
+            return;
+        }
+
+        if self.pending.is_empty() && byte != b'<' {
+            self.output.push_back(byte);
+            return;
+        }
+
+        self.pending.push(byte);
+        let len = self.pending.len();
+        if self.pending == Self::COMMENT || self.pending == Self::CDATA {
+            self.output.extend(self.pending.drain(..));
+            self.enter(if len == Self::COMMENT.len() {
+                State::Comment
+            } else {
+                State::CData
+            });
+        } else if [Self::DECLARATION, Self::COMMENT, Self::CDATA]
+            .iter()
+            .any(|prefix| prefix.starts_with(&self.pending))
+        {
+            // Keep matching
+        } else if len == Self::DECLARATION.len() + 1
+            && self.pending.starts_with(Self::DECLARATION)
+            && byte.is_ascii_whitespace()
+        {
+            // This is a declaration and not e.g. `<?xml-stylesheet`
+            let blank = self.offset > len as u64;
+            for byte in std::mem::take(&mut self.pending) {
+                self.emit(byte, blank);
+            }
+            self.enter(State::Declaration { blank });
+        } else {
+            // Not a declaration, but the last byte may start one
+            let last = if byte == b'<' {
+                self.pending.pop()
+            } else {
+                None
+            };
+            self.output.extend(self.pending.drain(..));
+            self.pending.extend(last);
+        }
+    }
+}
+
+impl<R: Read> Read for StripXmlDeclarations<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut chunk = [0u8; 4096];
+        while self.output.is_empty() && !self.eof {
+            let len = self.inner.read(&mut chunk)?;
+            if len == 0 {
+                self.eof = true;
+                self.output.extend(self.pending.drain(..));
+            }
+            for &byte in &chunk[..len] {
+                self.process(byte);
+            }
+        }
+
+        let len = buf.len().min(self.output.len());
+        for (dest, src) in buf.iter_mut().zip(self.output.drain(..len)) {
+            *dest = src;
+        }
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const V1DOT0: &str = include_str!("../fixtures/v1dot0_appendix_adot1.xml");
+    const V1DOT1: &str = include_str!("../fixtures/v1dot1_appendix_adot1.xml");
+    const V1DOT2: &str = include_str!("../fixtures/v1dot2_appendix_adot1.xml");
+
+    #[test]
+    fn strip_declarations() {
+        let input = "<?xml version=\"1.0\"?>\n<a/>\n<?xml version=\"1.0\"\n?><?xml-stylesheet href=\"x\"?><b/>";
+        let mut output = String::new();
+        StripXmlDeclarations::new(input.as_bytes())
+            .read_to_string(&mut output)
+            .unwrap();
+        assert_eq!(
+            output,
+            "<?xml version=\"1.0\"?>\n<a/>\n                   \n  <?xml-stylesheet href=\"x\"?><b/>"
+        );
+    }
+
+    #[test]
+    fn strip_declarations_outside_comments() {
+        let input = concat!(
+            "<a><!-- <?xml version=\"1.0\"?> --><![CDATA[<?xml version=\"1.0\"?>]]></a>",
+            "<?xml version=\"1.0\"?><b/>",
+        );
+        let mut output = String::new();
+        StripXmlDeclarations::new(input.as_bytes())
+            .read_to_string(&mut output)
+            .unwrap();
+        assert_eq!(
+            output,
+            concat!(
+                "<a><!-- <?xml version=\"1.0\"?> --><![CDATA[<?xml version=\"1.0\"?>]]></a>",
+                "                     <b/>",
+            )
+        );
+    }
+
+    #[test]
+    fn concatenated() {
+        let input = [V1DOT0, V1DOT1, V1DOT2].join("\n");
+        let alerts = Alerts::new(input.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(alerts.len(), 3);
+        assert!(matches!(alerts[0], crate::Alert::V1dot0(_)));
+        assert!(matches!(alerts[1], crate::Alert::V1dot1(_)));
+        assert!(matches!(alerts[2], crate::Alert::V1dot2(_)));
+    }
+
+    #[test]
+    fn wrapped() {
+        let strip = |xml: &str| xml.split_once("?>").unwrap().1.to_string();
+        let input = format!(
+            "<archive><day>{}</day><day>{}<note>not an alert</note></day></archive>",
+            strip(V1DOT1),
+            strip(V1DOT2)
+        );
+        let alerts = Alerts::new(input.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(alerts.len(), 2);
+    }
+
+    #[test]
+    fn errors() {
+        let invalid = "<alert xmlns=\"urn:oasis:names:tc:emergency:cap:1.2\"><sender/></alert>";
+        let input = format!("{}\n{}\n<unclosed>", invalid, V1DOT2);
+        let mut alerts = Alerts::new(input.as_bytes());
+        assert!(matches!(alerts.next(), Some(Err(StreamError::Alert(_)))));
+        assert!(matches!(alerts.next(), Some(Ok(_))));
+        assert!(matches!(alerts.next(), Some(Err(StreamError::Xml(_)))));
+        assert!(alerts.next().is_none());
+    }
+}
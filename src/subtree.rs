@@ -0,0 +1,105 @@
+//! Extracting XML elements from larger documents.
+
+use std::io::Read;
+use xml::name::OwnedName;
+use xml::reader::{EventReader, XmlEvent};
+use xml::writer::{EmitterConfig, EventWriter};
+
+/// The namespaces of the CAP versions this crate supports.
+const CAP_NAMESPACES: [&str; 3] = [
+    "http://www.incident.com/cap/1.0",
+    "urn:oasis:names:tc:emergency:cap:1.1",
+    "urn:oasis:names:tc:emergency:cap:1.2",
+];
+
+/// Whether `name` is in the namespace of a supported CAP version.
+pub(crate) fn is_cap(name: &OwnedName) -> bool {
+    CAP_NAMESPACES.contains(&name.namespace.as_deref().unwrap_or_default())
+}
+
+/// Whether `name` is the `<alert>` element of a supported CAP version.
+pub(crate) fn is_alert(name: &OwnedName) -> bool {
+    name.local_name == "alert" && is_cap(name)
+}
+
+/// Read the remainder of the element opened by `start`, returning it as a standalone XML string.
+///
+/// Namespace declarations inherited from the enclosing document are carried over onto the
+/// extracted element, so it parses identically on its own.
+pub(crate) fn read_element<R: Read>(
+    reader: &mut EventReader<R>,
+    start: XmlEvent,
+) -> Result<String, xml::reader::Error> {
+    let mut writer = EventWriter::new_with_config(
+        Vec::new(),
+        EmitterConfig::new()
+            .write_document_declaration(false)
+            .normalize_empty_elements(false),
+    );
+
+    let mut depth = 0usize;
+    let mut event = start;
+    loop {
+        match &event {
+            XmlEvent::StartElement { .. } => depth += 1,
+            XmlEvent::EndElement { .. } => depth -= 1,
+            _ => {}
+        }
+
+        if let Some(writer_event) = event.as_writer_event() {
+            writer.write(writer_event).map_err(invalid_data)?;
+        }
+
+        if depth == 0 {
+            break;
+        }
+        event = reader.next()?;
+    }
+
+    String::from_utf8(writer.into_inner()).map_err(invalid_data)
+}
+
+/// Report a failure to re-emit an element as a reader error, since the reader produced it.
+fn invalid_data<E>(e: E) -> xml::reader::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    std::io::Error::new(std::io::ErrorKind::InvalidData, e).into()
+}
+
+/// Skip the remainder of an element whose `StartElement` has just been read.
+#[cfg(feature = "feed")]
+pub(crate) fn skip_element<R: Read>(reader: &mut EventReader<R>) -> Result<(), xml::reader::Error> {
+    let mut depth = 1usize;
+    while depth > 0 {
+        match reader.next()? {
+            XmlEvent::StartElement { .. } => depth += 1,
+            XmlEvent::EndElement { .. } => depth -= 1,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Read the text content of an element whose `StartElement` has just been read, ignoring any child
+/// elements.
+#[cfg(feature = "feed")]
+pub(crate) fn read_text<R: Read>(
+    reader: &mut EventReader<R>,
+) -> Result<String, xml::reader::Error> {
+    let mut text = String::new();
+    let mut depth = 1usize;
+    while depth > 0 {
+        match reader.next()? {
+            XmlEvent::StartElement { .. } => depth += 1,
+            XmlEvent::EndElement { .. } => depth -= 1,
+            XmlEvent::Characters(s) | XmlEvent::CData(s) | XmlEvent::Whitespace(s)
+                if depth == 1 =>
+            {
+                text.push_str(&s)
+            }
+            _ => {}
+        }
+    }
+    Ok(text)
+}
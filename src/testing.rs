@@ -0,0 +1,85 @@
+//! Utilities for testing CAP fixtures against this crate.
+//!
+//! Requires the `test-util` feature.
+
+/// Assert that `xml` parses, and that the resulting alert round-trips unchanged.
+///
+/// This applies the same checks as this crate's own fixture tests:
+///
+/// * the alert round-trips through `to_string()` and `to_string_pretty()`
+/// * the alert round-trips through protobuf, if the `prost` feature is enabled
+/// * the alert upgraded by `into_latest()` round-trips in the same ways
+///
+/// # Panics
+///
+/// Panics if `xml` does not parse, or if any round-trip produces a different alert.
+///
+/// # Example
+///
+/// ```
+/// # let input = include_str!("../fixtures/v1dot2_appendix_adot1.xml");
+/// // let input: &str = /* CAP v1.2 appendix A.1 */;
+/// oasiscap::testing::assert_roundtrips(input);
+/// ```
+pub fn assert_roundtrips(xml: &str) {
+    let alert: crate::Alert = match xml.parse() {
+        Ok(alert) => alert,
+        Err(e) => panic!("error parsing alert: {}", e),
+    };
+
+    assert_alert_roundtrips("alert", &alert);
+
+    let upgraded = crate::Alert::from(alert.into_latest());
+    assert_alert_roundtrips("upgraded alert", &upgraded);
+}
+
+fn assert_alert_roundtrips(name: &str, alert: &crate::Alert) {
+    let roundtrip: crate::Alert = match alert.to_string().parse() {
+        Ok(alert) => alert,
+        Err(e) => panic!("error parsing {} XML: {}", name, e),
+    };
+    assert_eq!(
+        alert, &roundtrip,
+        "mismatch roundtripping {} through XML",
+        name
+    );
+
+    let pretty = match alert.to_string_pretty() {
+        Ok(xml) => xml,
+        Err(e) => panic!("error writing {} as pretty XML: {}", name, e),
+    };
+    let roundtrip: crate::Alert = match pretty.parse() {
+        Ok(alert) => alert,
+        Err(e) => panic!("error parsing {} pretty XML: {}", name, e),
+    };
+    assert_eq!(
+        alert, &roundtrip,
+        "mismatch roundtripping {} through pretty XML",
+        name
+    );
+
+    #[cfg(feature = "prost")]
+    assert_proto_roundtrips(name, alert);
+}
+
+#[cfg(feature = "prost")]
+fn assert_proto_roundtrips(name: &str, alert: &crate::Alert) {
+    use prost::Message;
+
+    let proto: crate::protobuf::Alert = alert.clone().into();
+    let bytes = proto.encode_length_delimited_to_vec();
+    let reproto = match crate::protobuf::Alert::decode_length_delimited(bytes.as_slice()) {
+        Ok(proto) => proto,
+        Err(e) => panic!("error decoding {} protobuf: {}", name, e),
+    };
+
+    let roundtrip = match crate::Alert::try_from(reproto) {
+        Ok(alert) => alert,
+        Err(e) => panic!("error converting {} from protobuf: {}", name, e),
+    };
+    assert_eq!(
+        &roundtrip, alert,
+        "mismatch roundtripping {} through protobuf",
+        name
+    );
+}
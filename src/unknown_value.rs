@@ -0,0 +1,33 @@
+/// The error returned when a string does not name a value of a CAP enumeration.
+///
+/// # Example
+///
+/// ```
+/// let err = "Weather".parse::<oasiscap::v1dot2::Category>().unwrap_err();
+/// assert_eq!(err.to_string(), "unknown Category value: \"Weather\"");
+/// ```
+#[derive(thiserror::Error, Debug, Clone, Eq, PartialEq)]
+#[error("unknown {type_name} value: {value:?}")]
+pub struct UnknownValueError {
+    type_name: &'static str,
+    value: String,
+}
+
+impl UnknownValueError {
+    pub(crate) fn new(type_name: &'static str, value: &str) -> Self {
+        Self {
+            type_name,
+            value: value.into(),
+        }
+    }
+
+    /// The name of the type which was being parsed.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The string which could not be parsed.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
@@ -0,0 +1,99 @@
+//! Reporting what changes when an alert is upgraded to the latest CAP version.
+
+use crate::{v1dot0, v1dot2, Alert};
+
+/// A change made to an alert's content while upgrading it to CAP v1.2.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum UpgradeChange {
+    /// The CAP v1.0 `password` was discarded, since CAP v1.1 removed it.
+    PasswordDropped,
+    /// `Certainty::VeryLikely` in the `Info` at this index was replaced with `Certainty::Likely`,
+    /// since CAP v1.1 removed `VeryLikely`.
+    VeryLikelyToLikely {
+        /// The index of the `Info`
+        info: usize,
+    },
+    /// A `Resource` had no `mime_type`, which CAP v1.2 requires, so `application/octet-stream` was
+    /// supplied.
+    MimeTypeDefaulted {
+        /// The index of the `Info`
+        info: usize,
+        /// The index of the `Resource` within the `Info`
+        resource: usize,
+    },
+}
+
+/// The changes made while upgrading an alert to CAP v1.2, as returned by
+/// `Alert::into_latest_with_report()`.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct UpgradeReport {
+    /// Every change made, in document order.
+    pub changes: Vec<UpgradeChange>,
+}
+
+impl UpgradeReport {
+    /// Returns `true` if the upgraded alert carries exactly the same information as the original.
+    pub fn is_lossless(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl Alert {
+    /// Return this alert as the latest supported alert version, along with a report of every
+    /// change this required.
+    ///
+    /// This upgrades the alert exactly as `into_latest()` does. CAP v1.2 `ResponseType` is a
+    /// superset of CAP v1.1, so response types are never changed.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot0_appendix_adot3.xml");
+    /// // let input: &str = /* CAP v1.0 appendix A.3 */;
+    /// use oasiscap::{Alert, UpgradeChange};
+    /// let alert: Alert = input.parse().unwrap();
+    ///
+    /// let (upgraded, report) = alert.clone().into_latest_with_report();
+    /// assert_eq!(upgraded, alert.into_latest());
+    /// assert_eq!(report.changes, vec![UpgradeChange::VeryLikelyToLikely { info: 0 }]);
+    /// assert!(!report.is_lossless());
+    /// ```
+    pub fn into_latest_with_report(self) -> (v1dot2::Alert, UpgradeReport) {
+        let mut changes = Vec::new();
+
+        match &self {
+            Alert::V1dot0(alert) => {
+                if alert.password.is_some() {
+                    changes.push(UpgradeChange::PasswordDropped);
+                }
+                for (i, info) in alert.info.iter().enumerate() {
+                    if info.certainty == v1dot0::Certainty::VeryLikely {
+                        changes.push(UpgradeChange::VeryLikelyToLikely { info: i });
+                    }
+                    let mime_types = info.resources.iter().map(|r| r.mime_type.is_some());
+                    defaulted_mime_types(&mut changes, i, mime_types);
+                }
+            }
+            Alert::V1dot1(alert) => {
+                for (i, info) in alert.info.iter().enumerate() {
+                    let mime_types = info.resources.iter().map(|r| r.mime_type.is_some());
+                    defaulted_mime_types(&mut changes, i, mime_types);
+                }
+            }
+            Alert::V1dot2(_) => {}
+        }
+
+        (self.into_latest(), UpgradeReport { changes })
+    }
+}
+
+/// Record a `MimeTypeDefaulted` for each resource of an `Info` which lacks a MIME type.
+fn defaulted_mime_types(
+    changes: &mut Vec<UpgradeChange>,
+    info: usize,
+    has_mime_type: impl Iterator<Item = bool>,
+) {
+    for (resource, present) in has_mime_type.enumerate() {
+        if !present {
+            changes.push(UpgradeChange::MimeTypeDefaulted { info, resource });
+        }
+    }
+}
@@ -1,5 +1,63 @@
 use serde::de::Error;
 use serde::{Deserialize, Deserializer};
+use xml::reader::{EventReader, XmlEvent};
+
+/// How to handle URLs which are not valid as written.
+///
+/// Alerts in the wild often contain URLs which are not quite valid. By default, this crate guesses
+/// at what was meant, but consumers who need to detect malformed URLs can opt out using
+/// [`Alert::from_str_with_url_policy`](crate::Alert::from_str_with_url_policy).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum UrlPolicy {
+    /// Reject any URL which is not valid as written.
+    Strict,
+    /// Accept valid URLs unchanged, and additionally:
+    ///
+    /// * Treat `http://` and `https://` with nothing following them as an absent URL.
+    /// * Treat a bare domain name ending in `.com`, `.org`, `.net`, `.gov`, or `.us`, optionally
+    ///   followed by a path, as if it were prefixed with `http://`. For example, `www.fema.org`
+    ///   becomes `http://www.fema.org/`.
+    ///
+    /// Any other invalid URL is rejected. This is the behavior of `str::parse()`.
+    #[default]
+    Lenient,
+}
+
+/// Check that every `<web>` and `<uri>` in `xml`, an alert which has already been parsed, is a
+/// valid URL as written, as `UrlPolicy::Strict` requires.
+pub(crate) fn check_strict(xml: &str) -> Result<(), crate::ParseError> {
+    // The text of the current `<web>` or `<uri>` element, if any
+    let mut text = None;
+    for event in EventReader::new(xml.as_bytes()) {
+        match event {
+            Ok(XmlEvent::StartElement { name, .. }) => {
+                let is_url = matches!(name.local_name.as_str(), "web" | "uri")
+                    && crate::subtree::is_cap(&name);
+                text = is_url.then(String::new);
+            }
+            Ok(XmlEvent::Characters(s) | XmlEvent::CData(s) | XmlEvent::Whitespace(s)) => {
+                if let Some(text) = &mut text {
+                    text.push_str(&s);
+                }
+            }
+            Ok(XmlEvent::EndElement { .. }) => match text.take() {
+                // An empty element was already handled by the lenient parse
+                Some(text) if !text.is_empty() && url::Url::parse(&text).is_err() => {
+                    let message = format!("invalid URL: {:?}", text);
+                    return Err(crate::ParseError::new(
+                        xml,
+                        xml_serde::Error::custom(message),
+                    ));
+                }
+                _ => {}
+            },
+            Ok(_) => {}
+            // The document has already been parsed, so it is well-formed
+            Err(_) => break,
+        }
+    }
+    Ok(())
+}
 
 /// Deserialize optional URLs, being generous on failure.
 ///
@@ -75,6 +133,24 @@ mod tests {
         assert_eq!(de("https://").unwrap(), None);
     }
 
+    #[test]
+    fn test_strict() {
+        let alert = |web: &str| {
+            format!(
+                r#"<alert xmlns="urn:oasis:names:tc:emergency:cap:1.2"><info><web>{}</web></info></alert>"#,
+                web
+            )
+        };
+        let err = super::check_strict(&alert("www.fema.org")).unwrap_err();
+        assert_eq!(err.path(), Some("info[0].web"));
+        assert!(super::check_strict(&alert("http://")).is_err());
+        assert!(super::check_strict(&alert("http://www.fema.org")).is_ok());
+
+        // Elements outside the CAP namespace are not checked
+        let other = r#"<alert xmlns="urn:oasis:names:tc:emergency:cap:1.2"><web xmlns="urn:x">www.fema.org</web></alert>"#;
+        assert!(super::check_strict(other).is_ok());
+    }
+
     #[test]
     fn test_fixup() {
         assert_eq!(
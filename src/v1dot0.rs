@@ -29,6 +29,9 @@ pub use certainty::Certainty;
 pub mod map;
 pub use map::Map;
 
+mod google;
+pub use google::GoogleExtensions;
+
 use crate::delimited_items::Items;
 use crate::geo::{Circle, Polygon};
 use crate::id::Id;
@@ -151,16 +154,62 @@ pub struct Alert {
 }
 
 #[derive(Serialize, Deserialize)]
-struct AlertDocument {
+struct AlertDocument<A> {
     #[serde(rename = "{http://www.incident.com/cap/1.0}cap:alert")]
-    alert: Alert,
+    alert: A,
+}
+
+impl Alert {
+    /// Write this alert as an XML document to `w`, as `oasiscap::Alert::write_xml()` does.
+    pub fn write_xml<W: std::io::Write>(&self, w: W) -> Result<(), crate::Error> {
+        crate::rewrite::write_xml(&AlertDocument { alert: self }, w)
+    }
+
+    /// Serialize this alert as XML, with each element on its own line and indented by two spaces.
+    ///
+    /// The output parses back into an identical alert, which makes it suitable for logs and fixture
+    /// files.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot0_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.0 appendix A.1 */;
+    /// let alert: oasiscap::v1dot0::Alert = input.parse().unwrap();
+    ///
+    /// let pretty = alert.to_string_pretty().unwrap();
+    /// assert!(pretty.contains("\n  <cap:identifier>"));
+    /// assert_eq!(pretty.parse::<oasiscap::v1dot0::Alert>().unwrap(), alert);
+    /// ```
+    pub fn to_string_pretty(&self) -> Result<String, crate::Error> {
+        crate::rewrite::to_string_pretty(&AlertDocument { alert: self })
+    }
+
+    /// Serialize this alert as XML, according to `options`.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot0_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.0 appendix A.1 */;
+    /// let alert: oasiscap::v1dot0::Alert = input.parse().unwrap();
+    ///
+    /// let xml = alert.to_string_with(oasiscap::XmlOutputOptions {
+    ///     default_namespace: true,
+    ///     indent: true,
+    /// }).unwrap();
+    /// let root = &xml[xml.find("<alert ").unwrap()..];
+    /// let root = &root[..root.find('>').unwrap()];
+    /// assert!(root.contains(r#"xmlns="http://www.incident.com/cap/1.0""#));
+    /// assert!(xml.contains("\n  <identifier>"));
+    /// assert_eq!(xml.parse::<oasiscap::v1dot0::Alert>().unwrap(), alert);
+    /// ```
+    pub fn to_string_with(&self, options: crate::XmlOutputOptions) -> Result<String, crate::Error> {
+        crate::rewrite::to_string_with(&AlertDocument { alert: self }, options)
+    }
 }
 
 impl std::str::FromStr for Alert {
-    type Err = xml_serde::Error;
+    type Err = crate::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        xml_serde::from_str::<AlertDocument>(s).map(|doc| doc.alert)
+        crate::parse_error::from_str::<AlertDocument<Alert>>(s).map(|doc| doc.alert)
     }
 }
 
@@ -351,6 +400,8 @@ pub struct Resource {
     /// Approximate size of the resource file in bytes
     #[serde(
         rename = "{http://www.incident.com/cap/1.0}cap:size",
+        deserialize_with = "crate::number::deserialize_optional",
+        default,
         skip_serializing_if = "Option::is_none"
     )]
     pub size: Option<u64>,
@@ -415,6 +466,8 @@ pub struct Area {
     /// WGS 84 mean sea level.
     #[serde(
         rename = "{http://www.incident.com/cap/1.0}cap:altitude",
+        deserialize_with = "crate::number::deserialize_optional",
+        default,
         skip_serializing_if = "Option::is_none"
     )]
     pub altitude: Option<f64>,
@@ -423,10 +476,39 @@ pub struct Area {
     /// sea level.
     #[serde(
         rename = "{http://www.incident.com/cap/1.0}cap:ceiling",
+        deserialize_with = "crate::number::deserialize_optional",
+        default,
         skip_serializing_if = "Option::is_none"
     )]
     pub ceiling: Option<f64>,
 }
 
+impl Area {
+    /// Check each of `circles` again, returning them if they are all still valid.
+    ///
+    /// Every CAP version shares `geo::Circle`, and parsing always produces valid circles. However,
+    /// `Circle`'s fields are public, so a circle may have been given an invalid `radius` since.
+    /// This checks each circle as `Circle::new()` would, which is useful before handing v1.0
+    /// geometry to code which assumes it is valid. (`Polygon`s cannot be made invalid after
+    /// construction, so they need no such check.)
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot0_appendix_adot3.xml");
+    /// // let input: &str = /* CAP v1.0 appendix A.3 */;
+    /// let alert: oasiscap::v1dot0::Alert = input.parse().unwrap();
+    /// let mut area = alert.info[0].areas[0].clone();
+    /// assert_eq!(area.validated_circles().unwrap(), area.circles);
+    ///
+    /// area.circles[0].radius = f64::NAN;
+    /// assert!(area.validated_circles().is_err());
+    /// ```
+    pub fn validated_circles(&self) -> Result<Vec<Circle>, crate::geo::InvalidCircleError> {
+        self.circles
+            .iter()
+            .map(|circle| Circle::new(circle.center, circle.radius))
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests;
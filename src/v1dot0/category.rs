@@ -26,3 +26,64 @@ pub enum Category {
     /// Other events
     Other,
 }
+
+impl Category {
+    /// Every `Category`, in the order listed by the specification.
+    pub const ALL: &[Category] = &[
+        Category::Geo,
+        Category::Met,
+        Category::Safety,
+        Category::Security,
+        Category::Rescue,
+        Category::Fire,
+        Category::Health,
+        Category::Env,
+        Category::Transport,
+        Category::Infra,
+        Category::Other,
+    ];
+
+    /// Returns the name of the `Category` as a `&str`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Category::Geo => "Geo",
+            Category::Met => "Met",
+            Category::Safety => "Safety",
+            Category::Security => "Security",
+            Category::Rescue => "Rescue",
+            Category::Fire => "Fire",
+            Category::Health => "Health",
+            Category::Env => "Env",
+            Category::Transport => "Transport",
+            Category::Infra => "Infra",
+            Category::Other => "Other",
+        }
+    }
+}
+
+impl std::fmt::Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for Category {
+    type Err = crate::UnknownValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Geo" => Ok(Category::Geo),
+            "Met" => Ok(Category::Met),
+            "Safety" => Ok(Category::Safety),
+            "Security" => Ok(Category::Security),
+            "Rescue" => Ok(Category::Rescue),
+            "Fire" => Ok(Category::Fire),
+            "Health" => Ok(Category::Health),
+            "Env" => Ok(Category::Env),
+            "Transport" => Ok(Category::Transport),
+            "Infra" => Ok(Category::Infra),
+            "Other" => Ok(Category::Other),
+            _ => Err(crate::UnknownValueError::new("Category", s)),
+        }
+    }
+}
@@ -19,6 +19,17 @@ use serde::{Deserialize, Serialize};
 ///     assert_tokens(&value, &[Token::UnitVariant{ name: "Certainty", variant: value.name() }]);
 /// }
 /// ```
+///
+/// # Ordering
+///
+/// `Certainty` is ordered by confidence, so `VeryLikely` is the greatest. `Unknown` sorts lowest.
+///
+/// ```
+/// use oasiscap::v1dot0::Certainty;
+///
+/// assert!(Certainty::VeryLikely > Certainty::Likely);
+/// assert!(Certainty::Unlikely > Certainty::Unknown);
+/// ```
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Certainty {
     /// Highly likely (p > ~ 85%) or certain
@@ -41,6 +52,15 @@ pub enum Certainty {
 }
 
 impl Certainty {
+    /// Every `Certainty`, in the order listed by the specification.
+    pub const ALL: &[Certainty] = &[
+        Certainty::VeryLikely,
+        Certainty::Likely,
+        Certainty::Possible,
+        Certainty::Unlikely,
+        Certainty::Unknown,
+    ];
+
     /// Returns the name of the `Certainty` as a `&str`.
     pub fn name(&self) -> &'static str {
         match self {
@@ -69,3 +89,31 @@ impl std::fmt::Display for Certainty {
         f.write_str(self.name())
     }
 }
+
+impl std::str::FromStr for Certainty {
+    type Err = crate::UnknownValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Very Likely" => Ok(Certainty::VeryLikely),
+            "Likely" => Ok(Certainty::Likely),
+            "Possible" => Ok(Certainty::Possible),
+            "Unlikely" => Ok(Certainty::Unlikely),
+            "Unknown" => Ok(Certainty::Unknown),
+            _ => Err(crate::UnknownValueError::new("Certainty", s)),
+        }
+    }
+}
+
+impl PartialOrd for Certainty {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Certainty {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Variants are declared from greatest to least, so compare discriminants in reverse
+        (*other as u8).cmp(&(*self as u8))
+    }
+}
@@ -0,0 +1,105 @@
+//! Elements from [Google's Public Alert extended CAP v1.0 schema].
+//!
+//! The extended schema allows `<derefUri>` inside `<resource>` as in CAP v1.1. CAP v1.0 has no
+//! such element, so `v1dot0::Resource` has nowhere to put it and `str::parse()` discards it.
+//! `Alert::from_str_google_extended()` captures it separately instead.
+//!
+//! [Google's Public Alert extended CAP v1.0 schema]: https://github.com/google/cap-library/blob/master/schema/cap10_extended.xsd
+
+use super::Alert;
+use crate::EmbeddedContent;
+use serde::Deserialize;
+
+/// Elements from Google's extended CAP v1.0 schema, as captured by
+/// `Alert::from_str_google_extended()`.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct GoogleExtensions {
+    /// The `<derefUri>` of each resource
+    ///
+    /// This has one entry per `Alert::info`, each of which has one entry per `Info::resources`, in
+    /// the same order.
+    pub embedded_content: Vec<Vec<Option<EmbeddedContent>>>,
+}
+
+impl GoogleExtensions {
+    /// Returns `true` if no resource has embedded content.
+    pub fn is_empty(&self) -> bool {
+        self.embedded_content
+            .iter()
+            .all(|resources| resources.iter().all(Option::is_none))
+    }
+}
+
+#[derive(Deserialize)]
+struct Document {
+    #[serde(rename = "{http://www.incident.com/cap/1.0}cap:alert")]
+    alert: ExtendedAlert,
+}
+
+#[derive(Deserialize)]
+struct ExtendedAlert {
+    #[serde(rename = "{http://www.incident.com/cap/1.0}cap:info", default)]
+    info: Vec<ExtendedInfo>,
+}
+
+#[derive(Deserialize)]
+struct ExtendedInfo {
+    #[serde(rename = "{http://www.incident.com/cap/1.0}cap:resource", default)]
+    resources: Vec<ExtendedResource>,
+}
+
+#[derive(Deserialize)]
+struct ExtendedResource {
+    #[serde(rename = "{http://www.incident.com/cap/1.0}cap:derefUri", default)]
+    embedded_content: Option<EmbeddedContent>,
+}
+
+impl Alert {
+    /// Parse an alert which may use Google's extended CAP v1.0 schema, also capturing any
+    /// `<derefUri>` elements.
+    ///
+    /// The alert itself is parsed exactly as `str::parse()` would. This reads the document twice,
+    /// so it's slower than `str::parse()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let input = include_str!("../../fixtures/v1dot0_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.0 appendix A.1 */;
+    /// use oasiscap::v1dot0::Alert;
+    ///
+    /// let (alert, extensions) = Alert::from_str_google_extended(input).unwrap();
+    /// assert_eq!(alert, input.parse().unwrap());
+    /// assert!(extensions.is_empty());
+    /// ```
+    pub fn from_str_google_extended(
+        s: &str,
+    ) -> Result<(Self, GoogleExtensions), crate::ParseError> {
+        let alert: Alert = s.parse()?;
+        let document: Document = crate::parse_error::from_str(s)?;
+        let embedded_content = document
+            .alert
+            .info
+            .into_iter()
+            .map(|info| {
+                info.resources
+                    .into_iter()
+                    .map(|resource| resource.embedded_content)
+                    .collect()
+            })
+            .collect();
+        Ok((alert, GoogleExtensions { embedded_content }))
+    }
+
+    /// Upgrade this alert to CAP v1.1, moving each resource's embedded content from `extensions`
+    /// into the corresponding `v1dot1::Resource`.
+    pub fn upgrade_with(self, extensions: GoogleExtensions) -> crate::v1dot1::Alert {
+        let mut alert = crate::v1dot1::Alert::from(self);
+        for (info, embedded_content) in alert.info.iter_mut().zip(extensions.embedded_content) {
+            for (resource, content) in info.resources.iter_mut().zip(embedded_content) {
+                resource.embedded_content = content;
+            }
+        }
+        alert
+    }
+}
@@ -14,3 +14,46 @@ pub enum MessageType {
     /// Indicates rejection of the message(s) identified in `references`; explanation SHOULD appear in `note`
     Error,
 }
+
+impl MessageType {
+    /// Every `MessageType`, in the order listed by the specification.
+    pub const ALL: &[MessageType] = &[
+        MessageType::Alert,
+        MessageType::Update,
+        MessageType::Cancel,
+        MessageType::Ack,
+        MessageType::Error,
+    ];
+
+    /// Returns the name of the `MessageType` as a `&str`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MessageType::Alert => "Alert",
+            MessageType::Update => "Update",
+            MessageType::Cancel => "Cancel",
+            MessageType::Ack => "Ack",
+            MessageType::Error => "Error",
+        }
+    }
+}
+
+impl std::fmt::Display for MessageType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for MessageType {
+    type Err = crate::UnknownValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Alert" => Ok(MessageType::Alert),
+            "Update" => Ok(MessageType::Update),
+            "Cancel" => Ok(MessageType::Cancel),
+            "Ack" => Ok(MessageType::Ack),
+            "Error" => Ok(MessageType::Error),
+            _ => Err(crate::UnknownValueError::new("MessageType", s)),
+        }
+    }
+}
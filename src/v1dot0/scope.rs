@@ -10,3 +10,36 @@ pub enum Scope {
     /// For dissemination only to specified addresses (see `addresses`)
     Private,
 }
+
+impl Scope {
+    /// Every `Scope`, in the order listed by the specification.
+    pub const ALL: &[Scope] = &[Scope::Public, Scope::Restricted, Scope::Private];
+
+    /// Returns the name of the `Scope` as a `&str`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Scope::Public => "Public",
+            Scope::Restricted => "Restricted",
+            Scope::Private => "Private",
+        }
+    }
+}
+
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for Scope {
+    type Err = crate::UnknownValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Public" => Ok(Scope::Public),
+            "Restricted" => Ok(Scope::Restricted),
+            "Private" => Ok(Scope::Private),
+            _ => Err(crate::UnknownValueError::new("Scope", s)),
+        }
+    }
+}
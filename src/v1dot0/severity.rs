@@ -1,6 +1,15 @@
 use serde::{Deserialize, Serialize};
 
 /// The expected impact of an alert to those it may affect.
+///
+/// `Severity` is ordered by intensity, so `Extreme` is the greatest. `Unknown` sorts lowest.
+///
+/// ```
+/// use oasiscap::v1dot0::Severity;
+///
+/// assert!(Severity::Extreme > Severity::Severe);
+/// assert!(Severity::Minor > Severity::Unknown);
+/// ```
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Severity {
     /// Extraordinary threat to life or property
@@ -16,6 +25,15 @@ pub enum Severity {
 }
 
 impl Severity {
+    /// Every `Severity`, in the order listed by the specification.
+    pub const ALL: &[Severity] = &[
+        Severity::Extreme,
+        Severity::Severe,
+        Severity::Moderate,
+        Severity::Minor,
+        Severity::Unknown,
+    ];
+
     /// Returns the name of the `Severity` as a `&str`.
     pub fn name(&self) -> &'static str {
         match self {
@@ -44,3 +62,31 @@ impl std::fmt::Display for Severity {
         f.write_str(self.name())
     }
 }
+
+impl std::str::FromStr for Severity {
+    type Err = crate::UnknownValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Extreme" => Ok(Severity::Extreme),
+            "Severe" => Ok(Severity::Severe),
+            "Moderate" => Ok(Severity::Moderate),
+            "Minor" => Ok(Severity::Minor),
+            "Unknown" => Ok(Severity::Unknown),
+            _ => Err(crate::UnknownValueError::new("Severity", s)),
+        }
+    }
+}
+
+impl PartialOrd for Severity {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Severity {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Variants are declared from greatest to least, so compare discriminants in reverse
+        (*other as u8).cmp(&(*self as u8))
+    }
+}
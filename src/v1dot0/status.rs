@@ -12,3 +12,43 @@ pub enum Status {
     /// Technical testing only, all recipients disregard
     Test,
 }
+
+impl Status {
+    /// Every `Status`, in the order listed by the specification.
+    pub const ALL: &[Status] = &[
+        Status::Actual,
+        Status::Exercise,
+        Status::System,
+        Status::Test,
+    ];
+
+    /// Returns the name of the `Status` as a `&str`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Actual => "Actual",
+            Status::Exercise => "Exercise",
+            Status::System => "System",
+            Status::Test => "Test",
+        }
+    }
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for Status {
+    type Err = crate::UnknownValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Actual" => Ok(Status::Actual),
+            "Exercise" => Ok(Status::Exercise),
+            "System" => Ok(Status::System),
+            "Test" => Ok(Status::Test),
+            _ => Err(crate::UnknownValueError::new("Status", s)),
+        }
+    }
+}
@@ -31,3 +31,38 @@ fn parse_appendix_adot4() {
         .unwrap();
     assert_eq!(alert.identifier, "KAR0-0306112239-SW");
 }
+
+#[test]
+fn enum_variants() {
+    assert_eq!(Status::ALL.len(), 4);
+    assert_eq!(MessageType::ALL.len(), 5);
+    assert_eq!(Scope::ALL.len(), 3);
+    assert_eq!(Category::ALL.len(), 11);
+    assert_eq!(Urgency::ALL.len(), 5);
+    assert_eq!(Severity::ALL.len(), 5);
+    assert_eq!(Certainty::ALL.len(), 5);
+
+    for value in Category::ALL {
+        assert_eq!(value.to_string().parse::<Category>().unwrap(), *value);
+    }
+}
+
+#[test]
+fn google_deref_uri() {
+    let input = include_str!("../../fixtures/v1dot0_appendix_adot1.xml").replace(
+        "</uri>",
+        "</uri>\n            <derefUri>R0lGODlhAQABAAAAACw=</derefUri>",
+    );
+
+    // Conforming v1.0 parsing discards the element
+    let alert: Alert = input.parse().unwrap();
+
+    let (extended, extensions) = Alert::from_str_google_extended(&input).unwrap();
+    assert_eq!(extended, alert);
+    assert!(!extensions.is_empty());
+    let content = extensions.embedded_content[0][0].clone().unwrap();
+    assert_eq!(content.to_string(), "R0lGODlhAQABAAAAACw=");
+
+    let alert: crate::v1dot2::Alert = alert.upgrade_with(extensions).into();
+    assert_eq!(alert.info[0].resources[0].embedded_content, Some(content));
+}
@@ -1,6 +1,16 @@
 use serde::{Deserialize, Serialize};
 
 /// The time-sensitivity of an alert.
+///
+/// `Urgency` is ordered by time-sensitivity, so `Immediate` is the greatest. `Unknown` sorts lowest,
+/// below `Past`.
+///
+/// ```
+/// use oasiscap::v1dot0::Urgency;
+///
+/// assert!(Urgency::Immediate > Urgency::Expected);
+/// assert!(Urgency::Past > Urgency::Unknown);
+/// ```
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Urgency {
     /// Responsive action SHOULD be taken immediately
@@ -14,3 +24,59 @@ pub enum Urgency {
     /// Urgency not known
     Unknown,
 }
+
+impl Urgency {
+    /// Every `Urgency`, in the order listed by the specification.
+    pub const ALL: &[Urgency] = &[
+        Urgency::Immediate,
+        Urgency::Expected,
+        Urgency::Future,
+        Urgency::Past,
+        Urgency::Unknown,
+    ];
+
+    /// Returns the name of the `Urgency` as a `&str`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Urgency::Immediate => "Immediate",
+            Urgency::Expected => "Expected",
+            Urgency::Future => "Future",
+            Urgency::Past => "Past",
+            Urgency::Unknown => "Unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for Urgency {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for Urgency {
+    type Err = crate::UnknownValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Immediate" => Ok(Urgency::Immediate),
+            "Expected" => Ok(Urgency::Expected),
+            "Future" => Ok(Urgency::Future),
+            "Past" => Ok(Urgency::Past),
+            "Unknown" => Ok(Urgency::Unknown),
+            _ => Err(crate::UnknownValueError::new("Urgency", s)),
+        }
+    }
+}
+
+impl PartialOrd for Urgency {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Urgency {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Variants are declared from greatest to least, so compare discriminants in reverse
+        (*other as u8).cmp(&(*self as u8))
+    }
+}
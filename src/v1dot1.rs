@@ -179,18 +179,64 @@ pub struct Alert {
 }
 
 #[derive(Serialize, Deserialize)]
-struct AlertDocument {
+struct AlertDocument<A> {
     #[serde(
         rename = "{urn:oasis:names:tc:emergency:cap:1.1;https://docs.oasis-open.org/emergency/cap/v1.1/errata/approved/cap.xsd}cap:alert"
     )]
-    alert: Alert,
+    alert: A,
+}
+
+impl Alert {
+    /// Write this alert as an XML document to `w`, as `oasiscap::Alert::write_xml()` does.
+    pub fn write_xml<W: std::io::Write>(&self, w: W) -> Result<(), crate::Error> {
+        crate::rewrite::write_xml(&AlertDocument { alert: self }, w)
+    }
+
+    /// Serialize this alert as XML, with each element on its own line and indented by two spaces.
+    ///
+    /// The output parses back into an identical alert, which makes it suitable for logs and fixture
+    /// files.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot1_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.1 appendix A.1 */;
+    /// let alert: oasiscap::v1dot1::Alert = input.parse().unwrap();
+    ///
+    /// let pretty = alert.to_string_pretty().unwrap();
+    /// assert!(pretty.contains("\n  <cap:identifier>"));
+    /// assert_eq!(pretty.parse::<oasiscap::v1dot1::Alert>().unwrap(), alert);
+    /// ```
+    pub fn to_string_pretty(&self) -> Result<String, crate::Error> {
+        crate::rewrite::to_string_pretty(&AlertDocument { alert: self })
+    }
+
+    /// Serialize this alert as XML, according to `options`.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot1_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.1 appendix A.1 */;
+    /// let alert: oasiscap::v1dot1::Alert = input.parse().unwrap();
+    ///
+    /// let xml = alert.to_string_with(oasiscap::XmlOutputOptions {
+    ///     default_namespace: true,
+    ///     indent: true,
+    /// }).unwrap();
+    /// let root = &xml[xml.find("<alert ").unwrap()..];
+    /// let root = &root[..root.find('>').unwrap()];
+    /// assert!(root.contains(r#"xmlns="urn:oasis:names:tc:emergency:cap:1.1""#));
+    /// assert!(xml.contains("\n  <identifier>"));
+    /// assert_eq!(xml.parse::<oasiscap::v1dot1::Alert>().unwrap(), alert);
+    /// ```
+    pub fn to_string_with(&self, options: crate::XmlOutputOptions) -> Result<String, crate::Error> {
+        crate::rewrite::to_string_with(&AlertDocument { alert: self }, options)
+    }
 }
 
 impl std::str::FromStr for Alert {
-    type Err = xml_serde::Error;
+    type Err = crate::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        xml_serde::from_str::<AlertDocument>(s).map(|doc| doc.alert)
+        crate::parse_error::from_str::<AlertDocument<Alert>>(s).map(|doc| doc.alert)
     }
 }
 
@@ -454,6 +500,8 @@ pub struct Resource {
     /// Approximate size of the resource file in bytes
     #[serde(
         rename = "{urn:oasis:names:tc:emergency:cap:1.1;https://docs.oasis-open.org/emergency/cap/v1.1/errata/approved/cap.xsd}cap:size",
+        deserialize_with = "crate::number::deserialize_optional",
+        default,
         skip_serializing_if = "Option::is_none"
     )]
     pub size: Option<u64>,
@@ -490,6 +538,38 @@ pub struct Resource {
     pub digest: Option<crate::digest::Sha1>,
 }
 
+impl Resource {
+    /// Set `embedded_content`, updating `size` to match its decoded length.
+    ///
+    /// `digest` is not updated; see `compute_digest()`.
+    pub fn set_content(&mut self, content: crate::EmbeddedContent) {
+        self.size = Some(content.len() as u64);
+        self.embedded_content = Some(content);
+    }
+
+    /// Set `digest` to the SHA-1 digest of `embedded_content`, if any.
+    ///
+    /// `digest` is left unchanged when there is no embedded content. Requires the `sha1` feature.
+    #[cfg(feature = "sha1")]
+    pub fn compute_digest(&mut self) {
+        if let Some(content) = &self.embedded_content {
+            self.digest = Some(content.sha1());
+        }
+    }
+
+    /// Check `digest` against the SHA-1 digest of `embedded_content`.
+    ///
+    /// Returns `None` unless both `digest` and `embedded_content` are present. Requires the `sha1`
+    /// feature.
+    #[cfg(feature = "sha1")]
+    pub fn verify_digest(&self) -> Option<bool> {
+        match (&self.embedded_content, &self.digest) {
+            (Some(content), Some(digest)) => Some(content.sha1() == *digest),
+            _ => None,
+        }
+    }
+}
+
 impl From<crate::v1dot0::Resource> for Resource {
     fn from(prev: crate::v1dot0::Resource) -> Self {
         Self {
@@ -550,6 +630,8 @@ pub struct Area {
     /// WGS 84 mean sea level.
     #[serde(
         rename = "{urn:oasis:names:tc:emergency:cap:1.1;https://docs.oasis-open.org/emergency/cap/v1.1/errata/approved/cap.xsd}cap:altitude",
+        deserialize_with = "crate::number::deserialize_optional",
+        default,
         skip_serializing_if = "Option::is_none"
     )]
     pub altitude: Option<f64>,
@@ -558,6 +640,8 @@ pub struct Area {
     /// sea level.
     #[serde(
         rename = "{urn:oasis:names:tc:emergency:cap:1.1;https://docs.oasis-open.org/emergency/cap/v1.1/errata/approved/cap.xsd}cap:ceiling",
+        deserialize_with = "crate::number::deserialize_optional",
+        default,
         skip_serializing_if = "Option::is_none"
     )]
     pub ceiling: Option<f64>,
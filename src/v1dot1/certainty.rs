@@ -27,6 +27,17 @@ use serde::{Deserialize, Serialize};
 ///     &[Token::UnitVariant{ name: "Certainty", variant: "Likely" }],
 /// );
 /// ```
+///
+/// # Ordering
+///
+/// `Certainty` is ordered by confidence, so `Observed` is the greatest. `Unknown` sorts lowest.
+///
+/// ```
+/// use oasiscap::v1dot1::Certainty;
+///
+/// assert!(Certainty::Observed > Certainty::Likely);
+/// assert!(Certainty::Unlikely > Certainty::Unknown);
+/// ```
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Certainty {
     /// Determined to have occurred or to be ongoing
@@ -43,6 +54,15 @@ pub enum Certainty {
 }
 
 impl Certainty {
+    /// Every `Certainty`, in the order listed by the specification.
+    pub const ALL: &[Certainty] = &[
+        Certainty::Observed,
+        Certainty::Likely,
+        Certainty::Possible,
+        Certainty::Unlikely,
+        Certainty::Unknown,
+    ];
+
     /// Returns the name of the `Certainty` as a `&str`.
     pub fn name(&self) -> &'static str {
         match self {
@@ -72,6 +92,25 @@ impl std::fmt::Display for Certainty {
     }
 }
 
+impl std::str::FromStr for Certainty {
+    type Err = crate::UnknownValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Observed" => Ok(Certainty::Observed),
+            "Likely" => Ok(Certainty::Likely),
+            "Possible" => Ok(Certainty::Possible),
+            "Unlikely" => Ok(Certainty::Unlikely),
+            "Unknown" => Ok(Certainty::Unknown),
+            // For backward compatibility with CAP v1.0
+            "Very Likely" => Ok(Certainty::Likely),
+            _ => Err(crate::UnknownValueError::new("Certainty", s)),
+        }
+    }
+}
+
+/// CAP v1.1 removed `VeryLikely`, which becomes `Likely` as the specification recommends. Every
+/// other CAP v1.0 certainty is unchanged.
 impl From<crate::v1dot0::Certainty> for Certainty {
     fn from(value: crate::v1dot0::Certainty) -> Self {
         use crate::v1dot0::Certainty as V1dot0;
@@ -84,3 +123,16 @@ impl From<crate::v1dot0::Certainty> for Certainty {
         }
     }
 }
+
+impl PartialOrd for Certainty {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Certainty {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Variants are declared from greatest to least, so compare discriminants in reverse
+        (*other as u8).cmp(&(*self as u8))
+    }
+}
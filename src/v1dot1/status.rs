@@ -15,6 +15,50 @@ pub enum Status {
     Draft,
 }
 
+impl Status {
+    /// Every `Status`, in the order listed by the specification.
+    pub const ALL: &[Status] = &[
+        Status::Actual,
+        Status::Exercise,
+        Status::System,
+        Status::Test,
+        Status::Draft,
+    ];
+
+    /// Returns the name of the `Status` as a `&str`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Actual => "Actual",
+            Status::Exercise => "Exercise",
+            Status::System => "System",
+            Status::Test => "Test",
+            Status::Draft => "Draft",
+        }
+    }
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for Status {
+    type Err = crate::UnknownValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Actual" => Ok(Status::Actual),
+            "Exercise" => Ok(Status::Exercise),
+            "System" => Ok(Status::System),
+            "Test" => Ok(Status::Test),
+            "Draft" => Ok(Status::Draft),
+            _ => Err(crate::UnknownValueError::new("Status", s)),
+        }
+    }
+}
+
+/// Every CAP v1.0 status is also a CAP v1.1 status.
 impl From<crate::v1dot0::Status> for Status {
     fn from(s: crate::v1dot0::Status) -> Self {
         use crate::v1dot0::Status as Prev;
@@ -31,3 +31,32 @@ fn parse_appendix_adot4() {
         .unwrap();
     assert_eq!(alert.identifier, "KAR0-0306112239-SW");
 }
+
+#[test]
+fn parse_bare_namespace() {
+    // The appendix fixtures declare the bare namespace, without any schema location
+    let input = include_str!("../../fixtures/v1dot1_appendix_adot1.xml");
+    assert!(input.contains(r#"xmlns = "urn:oasis:names:tc:emergency:cap:1.1">"#));
+
+    let alert: crate::Alert = input.parse().unwrap();
+    assert!(matches!(alert, crate::Alert::V1dot1(_)));
+    assert_eq!(
+        alert.xml_namespace(),
+        "urn:oasis:names:tc:emergency:cap:1.1"
+    );
+
+    let roundtrip: crate::Alert = alert.to_string().parse().unwrap();
+    assert_eq!(alert, roundtrip);
+}
+
+#[test]
+fn enum_variants() {
+    assert_eq!(Status::ALL.len(), 5);
+    assert_eq!(Category::ALL.len(), 12);
+    assert_eq!(ResponseType::ALL.len(), 7);
+    assert_eq!(Certainty::ALL.len(), 5);
+
+    for value in ResponseType::ALL {
+        assert_eq!(value.to_string().parse::<ResponseType>().unwrap(), *value);
+    }
+}
@@ -3,6 +3,22 @@
 //! Some of the types in this module are re-exported from the `v1dot0` and `v1dot1` modules, since
 //! they did not change between specifications.
 //!
+//! Enumerations which did change implement `From` their earlier versions, so individual values can
+//! be converted without upgrading a whole alert. These are the same conversions used by
+//! `Alert::into_latest()`:
+//!
+//! ```rust
+//! use oasiscap::{v1dot0, v1dot2};
+//!
+//! // CAP v1.1 removed `VeryLikely`, and recommends `Likely` in its place
+//! assert_eq!(v1dot2::Certainty::from(v1dot0::Certainty::VeryLikely), v1dot2::Certainty::Likely);
+//! assert_eq!(v1dot2::Category::from(v1dot0::Category::Geo), v1dot2::Category::Geo);
+//! assert_eq!(v1dot2::Status::from(v1dot0::Status::Test), v1dot2::Status::Test);
+//!
+//! // `MessageType`, `Scope`, `Severity`, and `Urgency` are unchanged since CAP v1.0
+//! let _: v1dot2::Urgency = v1dot0::Urgency::Immediate;
+//! ```
+//!
 //! # Example
 //!
 //! ```rust
@@ -42,12 +58,36 @@ use serde::{Deserialize, Serialize};
 pub use crate::v1dot0::{MessageType, Scope, Severity, Urgency};
 pub use crate::v1dot1::{Category, Certainty, Status};
 
+mod conformance;
+pub use conformance::{Conformance, StrictParseError, UrlIssue, DEFAULT_URL_SCHEMES};
+
+mod delta;
+pub use delta::{AlertDelta, FieldChange};
+
+mod example;
+
+mod extensions;
+pub use extensions::Extensions;
+
+#[cfg(feature = "geojson")]
+mod geojson;
+
+mod lifecycle;
+
 mod map;
 pub use map::Map;
 
+#[cfg(feature = "geo")]
+mod merge;
+
+mod path;
+
 mod response_type;
 pub use response_type::ResponseType;
 
+mod summary;
+pub use summary::AlertSummary;
+
 use crate::delimited_items::Items;
 use crate::geo::{Circle, Polygon};
 use crate::id::Id;
@@ -55,6 +95,10 @@ use crate::language::Language;
 use crate::references::References;
 
 /// A CAP v1.2 alert message.
+///
+/// Parsing a `v1dot2::Alert` directly, rather than an `oasiscap::Alert`, skips detecting the CAP
+/// version. Documents in any other namespace are rejected; use `Alert::from_str_assume_v1dot2()` to
+/// find out why.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename = "{urn:oasis:names:tc:emergency:cap:1.2;}cap:alert")]
 pub struct Alert {
@@ -151,6 +195,283 @@ pub struct Alert {
     pub info: Vec<Info>,
 }
 
+impl Alert {
+    /// The category used by the most `Info` blocks in this alert, or `None` if no `Info` has a
+    /// category.
+    ///
+    /// Each `Info` counts once towards each of its categories. Ties are broken in favor of the
+    /// category listed first by the CAP specification: `Geo`, `Met`, `Safety`, `Security`,
+    /// `Rescue`, `Fire`, `Health`, `Env`, `Transport`, `Infra`, `CBRNE`, `Other`.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot3.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.3 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    /// assert_eq!(alert.dominant_category(), Some(oasiscap::v1dot2::Category::Geo));
+    /// ```
+    pub fn dominant_category(&self) -> Option<Category> {
+        let mut best: Option<(Category, usize)> = None;
+        for &category in Category::ALL {
+            let count = self
+                .info
+                .iter()
+                .filter(|info| info.categories.contains(&category))
+                .count();
+            if count > 0 && best.is_none_or(|(_, best_count)| count > best_count) {
+                best = Some((category, count));
+            }
+        }
+        best.map(|(category, _)| category)
+    }
+
+    /// The `Info` with the greatest `severity`, breaking ties by `urgency` and then by `certainty`,
+    /// or `None` if this alert has no `Info`.
+    ///
+    /// This is the `Info` which alert displays commonly treat as the headline hazard. If several
+    /// are equally severe, urgent, and certain, the first is returned.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot3.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.3 */;
+    /// let mut alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    ///
+    /// let mut aftershock = alert.info[0].clone();
+    /// aftershock.headline = Some("Aftershock".into());
+    /// aftershock.severity = oasiscap::v1dot2::Severity::Moderate;
+    /// alert.info.push(aftershock);
+    ///
+    /// let primary = alert.primary_info().unwrap();
+    /// assert_eq!(primary.headline.as_deref(), Some("Aftershock"));
+    /// ```
+    pub fn primary_info(&self) -> Option<&Info> {
+        self.info
+            .iter()
+            .rev()
+            .max_by_key(|info| (info.severity, info.urgency, info.certainty))
+    }
+
+    /// The `Info` blocks whose language matches `lang`, in document order.
+    ///
+    /// Languages are matched using `Language::matches()`, so `en` matches `en-US`. If no `Info`
+    /// matches, this returns the `Info` blocks matching `en-US` instead, which includes any which do
+    /// not specify a language.
+    ///
+    /// All matching `Info` blocks are returned, since later blocks in the same language may expand
+    /// upon earlier ones.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot4.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.4 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    ///
+    /// let info = alert.info_for_language("es");
+    /// assert_eq!(info.len(), 1);
+    /// assert_eq!(info[0].headline.as_deref(), Some("Alerta Amber en el condado de Los Ángeles"));
+    ///
+    /// let info = alert.info_for_language("EN-us");
+    /// assert_eq!(info.len(), 1);
+    /// assert_eq!(info[0].headline.as_deref(), Some("Amber Alert in Los Angeles County"));
+    ///
+    /// // Unmatched languages fall back to `en-US`
+    /// assert_eq!(alert.info_for_language("fr"), info);
+    /// ```
+    pub fn info_for_language(&self, lang: &str) -> Vec<&Info> {
+        crate::language::select(&self.info, |info| &info.language, lang)
+    }
+
+    /// The indices of the `Info` blocks in each language, in order of first appearance.
+    ///
+    /// CAP treats the `Info` blocks sharing a language as a sequence, in which later blocks may
+    /// expand upon earlier ones. Languages are compared ignoring case, and an unspecified language
+    /// is grouped with `en-US`. Each group is keyed by the language of its first `Info` block.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot4.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.4 */;
+    /// let mut alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    ///
+    /// let groups = alert.language_groups();
+    /// assert_eq!(groups.len(), 2);
+    /// assert_eq!(groups[0].0, "en-US");
+    /// assert_eq!(groups[0].1, vec![0]);
+    /// assert_eq!(groups[1].0, "es-US");
+    /// assert_eq!(groups[1].1, vec![1]);
+    ///
+    /// // An additional block without a language continues the `en-US` sequence
+    /// let mut update = alert.info[0].clone();
+    /// update.language = Default::default();
+    /// alert.info.push(update);
+    /// assert_eq!(alert.language_groups()[0].1, vec![0, 2]);
+    /// ```
+    pub fn language_groups(&self) -> Vec<(Language, Vec<usize>)> {
+        let mut groups: Vec<(Language, Vec<usize>)> = Vec::new();
+        for (index, info) in self.info.iter().enumerate() {
+            let language = info.language.as_str();
+            match groups
+                .iter_mut()
+                .find(|(group, _)| group.as_str().eq_ignore_ascii_case(language))
+            {
+                Some((_, indices)) => indices.push(index),
+                None => groups.push((info.language.clone(), vec![index])),
+            }
+        }
+        groups
+    }
+
+    /// The `Info` blocks which recommend `response_type`.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// use oasiscap::v1dot2::{Alert, ResponseType};
+    /// let alert: Alert = input.parse().unwrap();
+    /// assert_eq!(alert.info_with_response_type(ResponseType::Shelter).len(), 1);
+    /// assert!(alert.info_with_response_type(ResponseType::Evacuate).is_empty());
+    /// ```
+    pub fn info_with_response_type(&self, response_type: ResponseType) -> Vec<&Info> {
+        self.info
+            .iter()
+            .filter(|info| info.response_type.contains(&response_type))
+            .collect()
+    }
+
+    /// The `Info` blocks in `category`.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// use oasiscap::v1dot2::{Alert, Category};
+    /// let alert: Alert = input.parse().unwrap();
+    /// assert_eq!(alert.info_with_category(Category::Met).len(), 1);
+    /// assert!(alert.info_with_category(Category::Fire).is_empty());
+    /// ```
+    pub fn info_with_category(&self, category: Category) -> Vec<&Info> {
+        self.info
+            .iter()
+            .filter(|info| info.categories.contains(&category))
+            .collect()
+    }
+
+    /// Strip the embedded content from every `Resource`, as when forwarding this alert onto a
+    /// two-way network.
+    ///
+    /// `replacement_uri` is called for each resource which had embedded content, and may return a
+    /// `uri` from which the content can be retrieved instead. See `Resource::strip_embedded()`.
+    pub fn strip_all_embedded<F>(&mut self, mut replacement_uri: F)
+    where
+        F: FnMut(&Resource) -> Option<url::Url>,
+    {
+        for resource in self
+            .info
+            .iter_mut()
+            .flat_map(|info| info.resources.iter_mut())
+            .filter(|resource| resource.embedded_content.is_some())
+        {
+            let uri = replacement_uri(resource);
+            resource.strip_embedded(uri);
+        }
+    }
+
+    /// Append a copy of `template` in a different `language`, returning it for further editing.
+    ///
+    /// Multilingual alerts usually repeat the same `Info` with translated text, so this copies
+    /// every field from `template` except the language. The translatable fields still need to be
+    /// replaced by the caller.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.1 */;
+    /// let mut alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    ///
+    /// let template = alert.info[0].clone();
+    /// let info = alert.push_info_like(&template, "es-US".parse().unwrap());
+    /// info.headline = Some("Seguridad Nacional establece el código NARANJA".into());
+    ///
+    /// assert_eq!(alert.info.len(), 2);
+    /// assert_eq!(alert.info[1].language, "es-US");
+    /// assert_eq!(alert.info[1].event, alert.info[0].event);
+    /// assert_eq!(alert.info_for_language("es")[0].headline, alert.info[1].headline);
+    /// ```
+    pub fn push_info_like(&mut self, template: &Info, language: Language) -> &mut Info {
+        let mut info = template.clone();
+        info.language = language;
+        self.info.push(info);
+        self.info.last_mut().unwrap()
+    }
+
+    /// The times at which each `Info` takes effect, begins, and expires, with the defaults from the
+    /// specification filled in.
+    ///
+    /// Returns one `NormalizedTimes` per `Info`, in order. An `Info` without an `effective` time
+    /// takes effect when the alert was `sent`. There is no default for `onset` or `expires`.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.1 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    /// assert!(alert.info[0].effective.is_none());
+    ///
+    /// let times = alert.normalized_times();
+    /// assert_eq!(times.len(), 1);
+    /// assert_eq!(times[0].effective, alert.sent);
+    /// assert_eq!(times[0].onset, None);
+    /// ```
+    pub fn normalized_times(&self) -> Vec<NormalizedTimes> {
+        self.info
+            .iter()
+            .map(|info| NormalizedTimes {
+                effective: info.effective.unwrap_or(self.sent),
+                onset: info.onset,
+                expires: info.expires,
+            })
+            .collect()
+    }
+
+    /// Compare two alerts field-by-field, except that key-value maps are compared without regard
+    /// to the order of their entries.
+    ///
+    /// The derived `PartialEq` treats maps as ordered, so alerts which passed through an encoding
+    /// that reorders `eventCode`, `parameter`, or `geocode` entries may compare unequal even though
+    /// they carry the same information.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot3.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.3 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    ///
+    /// let mut parameters = alert.info[0].parameters.iter().collect::<Vec<_>>();
+    /// parameters.reverse();
+    /// let mut reordered = alert.clone();
+    /// reordered.info[0].parameters = parameters.into_iter().collect();
+    ///
+    /// assert_ne!(alert, reordered);
+    /// assert!(alert.semantically_eq(&reordered));
+    /// ```
+    pub fn semantically_eq(&self, other: &Alert) -> bool {
+        // Move the maps out of a copy of each alert, compare what remains, then compare the maps
+        fn split(alert: &Alert) -> (Alert, Vec<Map>) {
+            let mut alert = alert.clone();
+            let mut maps = Vec::new();
+            for info in &mut alert.info {
+                maps.push(std::mem::take(&mut info.event_codes));
+                maps.push(std::mem::take(&mut info.parameters));
+                for area in &mut info.areas {
+                    maps.push(std::mem::take(&mut area.geocode));
+                }
+            }
+            (alert, maps)
+        }
+
+        let (alert, maps) = split(self);
+        let (other, other_maps) = split(other);
+        alert == other
+            && maps
+                .iter()
+                .zip(&other_maps)
+                .all(|(map, other)| map.eq_unordered(other))
+    }
+}
+
 impl From<crate::v1dot1::Alert> for Alert {
     fn from(prev: crate::v1dot1::Alert) -> Self {
         Self {
@@ -179,16 +500,89 @@ impl From<crate::v1dot0::Alert> for Alert {
 }
 
 #[derive(Serialize, Deserialize)]
-struct AlertDocument {
+struct AlertDocument<A> {
     #[serde(rename = "{urn:oasis:names:tc:emergency:cap:1.2;}cap:alert")]
-    alert: Alert,
+    alert: A,
+}
+
+impl Alert {
+    /// Parse a document as a CAP v1.2 alert, without detecting its CAP version.
+    ///
+    /// This is `str::parse()`, except that a document in another namespace is reported as
+    /// `Error::WrongNamespace` or `Error::UnsupportedNamespace`, rather than as a `ParseError`
+    /// about whichever v1.2 element was missing. Callers which already know the version, e.g.
+    /// from the feed an alert came from, can use this to skip the work `oasiscap::Alert` does.
+    ///
+    /// ```
+    /// # let v1dot2 = include_str!("../fixtures/v1dot2_appendix_adot1.xml");
+    /// # let v1dot1 = include_str!("../fixtures/v1dot1_appendix_adot1.xml");
+    /// use oasiscap::v1dot2::Alert;
+    ///
+    /// assert!(Alert::from_str_assume_v1dot2(v1dot2).is_ok());
+    /// match Alert::from_str_assume_v1dot2(v1dot1) {
+    ///     Err(oasiscap::Error::WrongNamespace { expected, found }) => {
+    ///         assert_eq!(expected, "urn:oasis:names:tc:emergency:cap:1.2");
+    ///         assert_eq!(found, "urn:oasis:names:tc:emergency:cap:1.1");
+    ///     }
+    ///     other => panic!("unexpected result: {:?}", other),
+    /// }
+    /// ```
+    pub fn from_str_assume_v1dot2(s: &str) -> Result<Self, crate::Error> {
+        crate::parse_error::from_str::<AlertDocument<Alert>>(s)
+            .map(|doc| doc.alert)
+            .map_err(|e| crate::Error::expecting(s, "urn:oasis:names:tc:emergency:cap:1.2", e))
+    }
+
+    /// Write this alert as an XML document to `w`, as `oasiscap::Alert::write_xml()` does.
+    pub fn write_xml<W: std::io::Write>(&self, w: W) -> Result<(), crate::Error> {
+        crate::rewrite::write_xml(&AlertDocument { alert: self }, w)
+    }
+
+    /// Serialize this alert as XML, with each element on its own line and indented by two spaces.
+    ///
+    /// The output parses back into an identical alert, which makes it suitable for logs and fixture
+    /// files.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.1 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    ///
+    /// let pretty = alert.to_string_pretty().unwrap();
+    /// assert!(pretty.contains("\n  <cap:identifier>"));
+    /// assert_eq!(pretty.parse::<oasiscap::v1dot2::Alert>().unwrap(), alert);
+    /// ```
+    pub fn to_string_pretty(&self) -> Result<String, crate::Error> {
+        crate::rewrite::to_string_pretty(&AlertDocument { alert: self })
+    }
+
+    /// Serialize this alert as XML, according to `options`.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.1 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    ///
+    /// let xml = alert.to_string_with(oasiscap::XmlOutputOptions {
+    ///     default_namespace: true,
+    ///     indent: true,
+    /// }).unwrap();
+    /// let root = &xml[xml.find("<alert ").unwrap()..];
+    /// let root = &root[..root.find('>').unwrap()];
+    /// assert!(root.contains(r#"xmlns="urn:oasis:names:tc:emergency:cap:1.2""#));
+    /// assert!(xml.contains("\n  <identifier>"));
+    /// assert_eq!(xml.parse::<oasiscap::v1dot2::Alert>().unwrap(), alert);
+    /// ```
+    pub fn to_string_with(&self, options: crate::XmlOutputOptions) -> Result<String, crate::Error> {
+        crate::rewrite::to_string_with(&AlertDocument { alert: self }, options)
+    }
 }
 
 impl std::str::FromStr for Alert {
-    type Err = xml_serde::Error;
+    type Err = crate::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        xml_serde::from_str::<AlertDocument>(s).map(|doc| doc.alert)
+        crate::parse_error::from_str::<AlertDocument<Alert>>(s).map(|doc| doc.alert)
     }
 }
 
@@ -370,6 +764,291 @@ pub struct Info {
     pub areas: Vec<Area>,
 }
 
+impl Info {
+    /// The first event code in the coding system named `scheme`, e.g. `SAME`, if any.
+    ///
+    /// This is shorthand for `event_codes.get(scheme)`. Use `event_codes.get_all(scheme)` when a
+    /// scheme may appear more than once.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    /// assert_eq!(alert.info[0].event_code("SAME"), Some("SVR"));
+    /// assert_eq!(alert.info[0].event_code("NWS"), None);
+    /// ```
+    pub fn event_code(&self, scheme: &str) -> Option<&str> {
+        self.event_codes.get(scheme)
+    }
+
+    /// Returns `true` if any event code in the coding system named `scheme` is `value`.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    /// assert!(alert.info[0].has_event_code("SAME", "SVR"));
+    /// assert!(!alert.info[0].has_event_code("SAME", "TOR"));
+    /// ```
+    pub fn has_event_code(&self, scheme: &str, value: &str) -> bool {
+        self.event_codes.get_all(scheme).any(|v| v == value)
+    }
+
+    /// The period during which this `Info` is in effect, given the `sent` time of its alert.
+    ///
+    /// The window starts at `effective`, or at `sent` if there is no `effective` time, and ends at
+    /// `expires`. An end of `None` means the information does not expire.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    /// let (start, end) = alert.info[0].active_window(alert.sent);
+    /// assert_eq!(start, alert.sent);
+    /// assert_eq!(end, alert.info[0].expires);
+    /// assert!(end.is_some());
+    /// ```
+    pub fn active_window(&self, sent: DateTime) -> (DateTime, Option<DateTime>) {
+        (self.effective.unwrap_or(sent), self.expires)
+    }
+
+    /// Collapse each run of whitespace to a single space, and trim leading and trailing
+    /// whitespace, in the free-text fields of this `Info`.
+    ///
+    /// This affects `event`, `sender_name`, `headline`, `description`, `instruction`, and
+    /// `contact`. Parsing never does this, since originators may intend specific formatting.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.1 */;
+    /// let mut alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    /// let info = &mut alert.info[0];
+    /// assert!(info.description.as_deref().unwrap().contains("Advisory\n"));
+    ///
+    /// info.normalize_text();
+    /// let description = info.description.as_deref().unwrap();
+    /// assert!(description.contains(" Homeland Security Advisory System threat level "));
+    /// assert!(info.instruction.as_deref().unwrap().starts_with("A High Condition"));
+    /// ```
+    pub fn normalize_text(&mut self) {
+        let normalize =
+            |text: &mut String| *text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        normalize(&mut self.event);
+        for text in [
+            &mut self.sender_name,
+            &mut self.headline,
+            &mut self.description,
+            &mut self.instruction,
+            &mut self.contact,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            normalize(text);
+        }
+    }
+
+    /// Compose a short plain-text message from this `Info`, suitable for Wireless Emergency Alerts
+    /// and similar length-limited channels, containing at most `max_len` characters.
+    ///
+    /// WEA messages are limited to 90 characters, or 360 characters on newer handsets. The text is
+    /// composed in this order:
+    ///
+    /// 1. The `headline`, if present. Otherwise, the `event` followed by `for` and the description
+    ///    of each `Area`, separated by commas.
+    /// 2. The `instruction`, if present, separated from the above by a period if it does not
+    ///    already end with punctuation.
+    ///
+    /// Runs of whitespace are collapsed into single spaces. If the result is longer than `max_len`
+    /// characters, it is truncated at the last word boundary which fits, or in the middle of the
+    /// first word if even that does not fit.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    /// let mut info = alert.info[0].clone();
+    ///
+    /// assert_eq!(
+    ///     info.wea_text(90),
+    ///     "SEVERE THUNDERSTORM WARNING. TAKE COVER IN A SUBSTANTIAL SHELTER UNTIL THE STORM PASSES."
+    /// );
+    /// assert_eq!(info.wea_text(40), "SEVERE THUNDERSTORM WARNING. TAKE COVER");
+    ///
+    /// info.headline = None;
+    /// info.instruction = None;
+    /// assert_eq!(
+    ///     info.wea_text(90),
+    ///     "SEVERE THUNDERSTORM for EXTREME NORTH CENTRAL TUOLUMNE COUNTY IN CALIFORNIA, EXTREME"
+    /// );
+    /// ```
+    pub fn wea_text(&self, max_len: usize) -> String {
+        let mut text = match &self.headline {
+            Some(headline) => headline.clone(),
+            None => {
+                let mut text = self.event.clone();
+                if !self.areas.is_empty() {
+                    text.push_str(" for ");
+                    text.push_str(
+                        &self
+                            .areas
+                            .iter()
+                            .map(|area| area.description.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    );
+                }
+                text
+            }
+        };
+
+        if let Some(instruction) = &self.instruction {
+            if !text.trim_end().ends_with(['.', '!', '?']) {
+                text.push('.');
+            }
+            text.push(' ');
+            text.push_str(instruction);
+        }
+
+        truncate_words(&text, max_len)
+    }
+
+    /// Returns whether the `headline` is at most `max` characters long, or `None` if there is no
+    /// headline.
+    ///
+    /// CAP recommends keeping headlines to 160 characters, since they may be delivered to devices
+    /// which display little text.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    /// let mut info = alert.info[0].clone();
+    ///
+    /// assert_eq!(info.headline.as_deref(), Some("SEVERE THUNDERSTORM WARNING"));
+    /// assert_eq!(info.headline_is_within(160), Some(true));
+    /// assert_eq!(info.headline_is_within(20), Some(false));
+    ///
+    /// info.headline = None;
+    /// assert_eq!(info.headline_is_within(160), None);
+    /// ```
+    pub fn headline_is_within(&self, max: usize) -> Option<bool> {
+        self.headline
+            .as_ref()
+            .map(|headline| headline.chars().count() <= max)
+    }
+
+    /// Shorten the `headline` to at most `max` characters, if it is longer.
+    ///
+    /// The headline is cut at the last word boundary which leaves room for a trailing `…`, and
+    /// runs of whitespace are collapsed. Headlines which already fit, and missing headlines, are
+    /// left unchanged.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    /// let mut info = alert.info[0].clone();
+    ///
+    /// info.truncate_headline(160);
+    /// assert_eq!(info.headline.as_deref(), Some("SEVERE THUNDERSTORM WARNING"));
+    ///
+    /// info.truncate_headline(20);
+    /// assert_eq!(info.headline.as_deref(), Some("SEVERE THUNDERSTORM…"));
+    /// assert_eq!(info.headline_is_within(20), Some(true));
+    /// ```
+    pub fn truncate_headline(&mut self, max: usize) {
+        if let Some(headline) = &mut self.headline {
+            if headline.chars().count() > max {
+                *headline = match max {
+                    0 => String::new(),
+                    _ => truncate_words(headline, max - 1) + "…",
+                };
+            }
+        }
+    }
+}
+
+/// The times associated with an `Info`, as returned by `Alert::normalized_times()`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct NormalizedTimes {
+    /// The time at which the information takes effect, which is `sent` unless `effective` was
+    /// specified.
+    pub effective: DateTime,
+    /// The expected time of the beginning of the subject event, if specified.
+    pub onset: Option<DateTime>,
+    /// The time at which the information expires, if specified.
+    pub expires: Option<DateTime>,
+}
+
+/// Collapse runs of whitespace in `text`, then truncate it to at most `max_len` characters at the
+/// last word boundary which fits, or in the middle of the first word if even that does not fit.
+fn truncate_words(text: &str, max_len: usize) -> String {
+    let mut output = String::new();
+    let mut len = 0;
+    for word in text.split_whitespace() {
+        let word_len = word.chars().count();
+        if output.is_empty() {
+            if word_len > max_len {
+                return word.chars().take(max_len).collect();
+            }
+        } else if len + 1 + word_len > max_len {
+            break;
+        } else {
+            output.push(' ');
+            len += 1;
+        }
+        output.push_str(word);
+        len += word_len;
+    }
+    output
+}
+
+/// An `Info` with an empty `event`, `Unknown` urgency, severity, and certainty, and no other
+/// content.
+///
+/// This is not a useful `Info` by itself, but it makes constructing one by hand more convenient:
+///
+/// ```
+/// use oasiscap::v1dot2::{Category, Info, Severity};
+///
+/// let info = Info {
+///     categories: vec![Category::Met],
+///     event: "Thunderstorm".into(),
+///     severity: Severity::Severe,
+///     ..Default::default()
+/// };
+/// assert!(info.areas.is_empty());
+/// ```
+impl Default for Info {
+    fn default() -> Self {
+        Self {
+            language: Language::default(),
+            categories: Vec::new(),
+            event: String::new(),
+            response_type: Vec::new(),
+            urgency: Urgency::Unknown,
+            severity: Severity::Unknown,
+            certainty: Certainty::Unknown,
+            audience: None,
+            event_codes: Map::new(),
+            effective: None,
+            onset: None,
+            expires: None,
+            sender_name: None,
+            headline: None,
+            description: None,
+            instruction: None,
+            web: None,
+            contact: None,
+            parameters: Map::new(),
+            resources: Vec::new(),
+            areas: Vec::new(),
+        }
+    }
+}
+
 impl From<crate::v1dot1::Info> for Info {
     fn from(prev: crate::v1dot1::Info) -> Self {
         Self {
@@ -418,6 +1097,8 @@ pub struct Resource {
     /// Approximate size of the resource file in bytes
     #[serde(
         rename = "{urn:oasis:names:tc:emergency:cap:1.2;}cap:size",
+        deserialize_with = "crate::number::deserialize_optional",
+        default,
         skip_serializing_if = "Option::is_none"
     )]
     pub size: Option<u64>,
@@ -454,6 +1135,122 @@ pub struct Resource {
     pub digest: Option<crate::digest::Sha1>,
 }
 
+impl Resource {
+    /// Instantiate a `Resource` which carries its content inline, with `size` set to match.
+    ///
+    /// `digest` is not set; see `compute_digest()`.
+    ///
+    /// ```
+    /// use oasiscap::{v1dot2::Resource, EmbeddedContent};
+    /// let content = EmbeddedContent::from_bytes(b"GIF89a");
+    /// let resource = Resource::inline("Map of the affected area", "image/gif", content);
+    /// assert_eq!(resource.size, Some(6));
+    /// assert!(resource.uri.is_none());
+    /// ```
+    pub fn inline<D: Into<String>, M: Into<String>>(
+        description: D,
+        mime_type: M,
+        content: crate::EmbeddedContent,
+    ) -> Self {
+        let mut resource = Self {
+            description: description.into(),
+            mime_type: mime_type.into(),
+            size: None,
+            uri: None,
+            embedded_content: None,
+            digest: None,
+        };
+        resource.set_content(content);
+        resource
+    }
+
+    /// Instantiate a `Resource` which refers to its content by `uri`.
+    ///
+    /// `size` and `digest` are not set, since the content is not at hand.
+    ///
+    /// ```
+    /// use oasiscap::v1dot2::Resource;
+    /// let resource = Resource::linked(
+    ///     "Siren audio",
+    ///     "audio/mpeg",
+    ///     "https://example.com/siren.mp3".parse().unwrap(),
+    /// );
+    /// assert_eq!(resource.uri.unwrap().as_str(), "https://example.com/siren.mp3");
+    /// assert!(resource.embedded_content.is_none());
+    /// ```
+    pub fn linked<D: Into<String>, M: Into<String>>(
+        description: D,
+        mime_type: M,
+        uri: url::Url,
+    ) -> Self {
+        Self {
+            description: description.into(),
+            mime_type: mime_type.into(),
+            size: None,
+            uri: Some(uri),
+            embedded_content: None,
+            digest: None,
+        }
+    }
+
+    /// Set `embedded_content`, updating `size` to match its decoded length.
+    ///
+    /// `digest` is not updated; see `compute_digest()`.
+    pub fn set_content(&mut self, content: crate::EmbeddedContent) {
+        self.size = Some(content.len() as u64);
+        self.embedded_content = Some(content);
+    }
+
+    /// Remove `embedded_content`, setting `uri` to `replacement_uri` if provided.
+    ///
+    /// CAP requires that `derefUri` be stripped when a one-way message is forwarded onto a two-way
+    /// network, and recommends that a `uri` be supplied in its place. `size` and `digest` still
+    /// describe the content, so they are left intact.
+    ///
+    /// ```
+    /// use oasiscap::v1dot2::Resource;
+    /// let mut resource = Resource::inline(
+    ///     "Siren audio",
+    ///     "audio/mpeg",
+    ///     b"\xff\xfb\x90\x00".to_vec().into(),
+    /// );
+    ///
+    /// let uri: url::Url = "https://example.com/siren.mp3".parse().unwrap();
+    /// resource.strip_embedded(Some(uri.clone()));
+    /// assert!(resource.embedded_content.is_none());
+    /// assert_eq!(resource.uri, Some(uri));
+    /// assert_eq!(resource.size, Some(4));
+    /// ```
+    pub fn strip_embedded(&mut self, replacement_uri: Option<url::Url>) {
+        self.embedded_content = None;
+        if let Some(uri) = replacement_uri {
+            self.uri = Some(uri);
+        }
+    }
+
+    /// Set `digest` to the SHA-1 digest of `embedded_content`, if any.
+    ///
+    /// `digest` is left unchanged when there is no embedded content. Requires the `sha1` feature.
+    #[cfg(feature = "sha1")]
+    pub fn compute_digest(&mut self) {
+        if let Some(content) = &self.embedded_content {
+            self.digest = Some(content.sha1());
+        }
+    }
+
+    /// Check `digest` against the SHA-1 digest of `embedded_content`.
+    ///
+    /// Returns `None` unless both `digest` and `embedded_content` are present. Requires the `sha1`
+    /// feature.
+    #[cfg(feature = "sha1")]
+    pub fn verify_digest(&self) -> Option<bool> {
+        match (&self.embedded_content, &self.digest) {
+            (Some(content), Some(digest)) => Some(content.sha1() == *digest),
+            _ => None,
+        }
+    }
+}
+
 impl From<crate::v1dot1::Resource> for Resource {
     fn from(prev: crate::v1dot1::Resource) -> Self {
         Self {
@@ -471,7 +1268,7 @@ impl From<crate::v1dot1::Resource> for Resource {
 
 /// Geographical (and usually also geospatial) information describing the expected or actual
 /// location of the event.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename = "{urn:oasis:names:tc:emergency:cap:1.2;}cap:area")]
 pub struct Area {
     /// A text description of the affected area.
@@ -512,6 +1309,8 @@ pub struct Area {
     /// WGS 84 mean sea level.
     #[serde(
         rename = "{urn:oasis:names:tc:emergency:cap:1.2;}cap:altitude",
+        deserialize_with = "crate::number::deserialize_optional",
+        default,
         skip_serializing_if = "Option::is_none"
     )]
     pub altitude: Option<f64>,
@@ -519,12 +1318,147 @@ pub struct Area {
     /// The maximum altitude of the affected area of the alert message, in feet above WGS 84 mean
     /// sea level.
     #[serde(
-        rename = "{urn:oasis:names:tc:emergency:cap:1.1;https://docs.oasis-open.org/emergency/cap/v1.1/errata/approved/cap.xsd}cap:ceiling",
+        rename = "{urn:oasis:names:tc:emergency:cap:1.2;}cap:ceiling",
+        deserialize_with = "crate::number::deserialize_optional",
+        default,
         skip_serializing_if = "Option::is_none"
     )]
     pub ceiling: Option<f64>,
 }
 
+/// The length of an international foot, exactly.
+const METERS_PER_FOOT: f64 = 0.3048;
+
+impl Area {
+    /// The `altitude`, converted from feet to meters.
+    ///
+    /// Every CAP version stores altitudes as `f64`, so conversions in either direction are not
+    /// rounded, but converting meters to feet and back may differ from the original value in the
+    /// last few bits.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_altitude_ceiling.xml");
+    /// // let input: &str = /* a CAP v1.2 alert with an altitude */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    /// let mut area = alert.info[0].areas[0].clone();
+    /// assert_eq!(area.altitude, Some(10000.0));
+    /// assert_eq!(area.altitude_meters(), Some(3048.0));
+    ///
+    /// area.set_altitude_meters(Some(1000.0));
+    /// assert_eq!(area.altitude, Some(1000.0 / 0.3048));
+    /// ```
+    pub fn altitude_meters(&self) -> Option<f64> {
+        self.altitude.map(|feet| feet * METERS_PER_FOOT)
+    }
+
+    /// Set the `altitude` from a value in meters, converting it to feet.
+    pub fn set_altitude_meters(&mut self, meters: Option<f64>) {
+        self.altitude = meters.map(|meters| meters / METERS_PER_FOOT);
+    }
+
+    /// The `ceiling`, converted from feet to meters.
+    ///
+    /// See `altitude_meters()` regarding precision.
+    pub fn ceiling_meters(&self) -> Option<f64> {
+        self.ceiling.map(|feet| feet * METERS_PER_FOOT)
+    }
+
+    /// Set the `ceiling` from a value in meters, converting it to feet.
+    pub fn set_ceiling_meters(&mut self, meters: Option<f64>) {
+        self.ceiling = meters.map(|meters| meters / METERS_PER_FOOT);
+    }
+
+    /// The values of `geocode` entries named `SAME`, in document order.
+    ///
+    /// [Specific Area Message Encoding] codes are used by the US Emergency Alert System and NOAA
+    /// Weather Radio. Each is six digits: a subdivision digit (`0` for the whole county), followed
+    /// by the two-digit state and three-digit county FIPS codes.
+    ///
+    /// [Specific Area Message Encoding]: https://www.weather.gov/nwr/sameinfo
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    /// let area = &alert.info[0].areas[0];
+    /// assert_eq!(
+    ///     area.same_codes().collect::<Vec<_>>(),
+    ///     vec!["006109", "006009", "006003"]
+    /// );
+    /// assert!(area.same_codes().all(oasiscap::v1dot2::Area::is_valid_same_code));
+    /// assert_eq!(area.fips_codes().count(), 0);
+    /// ```
+    pub fn same_codes(&self) -> impl Iterator<Item = &str> {
+        self.geocode.get_all("SAME")
+    }
+
+    /// The values of `geocode` entries named `FIPS6`, in document order.
+    ///
+    /// These are six-digit US county codes: the two-digit state FIPS code followed by the
+    /// three-digit county FIPS code, sometimes with a leading `0`.
+    pub fn fips_codes(&self) -> impl Iterator<Item = &str> {
+        self.geocode.get_all("FIPS6")
+    }
+
+    /// Returns `true` if `code` has the form of a SAME code, i.e. exactly six ASCII digits.
+    ///
+    /// This does not check that the code refers to a real location.
+    ///
+    /// ```
+    /// use oasiscap::v1dot2::Area;
+    /// assert!(Area::is_valid_same_code("006109"));
+    /// assert!(!Area::is_valid_same_code("6109"));
+    /// assert!(!Area::is_valid_same_code("00610A"));
+    /// ```
+    pub fn is_valid_same_code(code: &str) -> bool {
+        code.len() == 6 && code.bytes().all(|b| b.is_ascii_digit())
+    }
+
+    /// Parse `s` as a polygon and add it to `polygons`.
+    ///
+    /// An empty or blank `s` is skipped, just as an empty `<polygon>` is when parsing an alert.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.1 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    /// let mut area = alert.info[0].areas[0].clone();
+    ///
+    /// area.add_polygon_str("38.47,-120.14 38.34,-119.95 38.52,-119.74 38.47,-120.14").unwrap();
+    /// area.add_polygon_str("").unwrap();
+    /// assert!(area.add_polygon_str("38.47,-120.14").is_err());
+    /// assert_eq!(area.polygons.len(), 1);
+    /// ```
+    pub fn add_polygon_str(&mut self, s: &str) -> Result<(), crate::geo::InvalidPolygonError> {
+        if !s.trim().is_empty() {
+            self.polygons.push(s.parse()?);
+        }
+        Ok(())
+    }
+
+    /// Parse `s` as a circle and add it to `circles`.
+    ///
+    /// An empty or blank `s` is skipped, as for `add_polygon_str()`.
+    ///
+    /// ```
+    /// # let input = include_str!("../fixtures/v1dot2_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.1 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    /// let mut area = alert.info[0].areas[0].clone();
+    ///
+    /// area.add_circle_str("32.9525,-115.5527 0").unwrap();
+    /// area.add_circle_str(" ").unwrap();
+    /// assert!(area.add_circle_str("32.9525,-115.5527").is_err());
+    /// assert_eq!(area.circles.len(), 1);
+    /// ```
+    pub fn add_circle_str(&mut self, s: &str) -> Result<(), crate::geo::InvalidCircleError> {
+        if !s.trim().is_empty() {
+            self.circles.push(s.parse()?);
+        }
+        Ok(())
+    }
+}
+
 impl From<crate::v1dot1::Area> for Area {
     fn from(prev: crate::v1dot1::Area) -> Self {
         Self {
@@ -0,0 +1,257 @@
+//! Strict validation against the CAP v1.2 specification.
+//!
+//! Parsing is deliberately lenient, so an `Alert` may hold content which is well-formed but which
+//! violates rules stated in the specification's prose. `Alert::validate()` checks for those.
+//! `Alert::from_str_strict()` additionally checks for violations which parsing erases, like empty
+//! `<polygon>` elements and `Z` timestamps.
+
+use super::{Alert, MessageType, Scope};
+use xml::reader::{EventReader, XmlEvent};
+
+const NAMESPACE: &str = "urn:oasis:names:tc:emergency:cap:1.2";
+
+/// A way in which an alert fails to conform to the CAP v1.2 specification.
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum Conformance {
+    /// A `Scope::Public` alert carries `restriction` or `addresses`, which only make sense for
+    /// `Scope::Restricted` and `Scope::Private` alerts respectively
+    #[error("public alert must not carry {0}")]
+    ContradictoryScope(&'static str),
+    /// An element which the specification requires is absent
+    #[error("missing required <{0}>")]
+    MissingElement(&'static str),
+    /// A `<polygon>` element is empty, rather than being omitted
+    #[error("empty <polygon>")]
+    EmptyPolygon,
+    /// A timestamp uses `Z` rather than a numeric offset for UTC
+    #[error("<{0}> uses an alphabetic time zone designator")]
+    AlphabeticTimeZone(&'static str),
+    /// A timestamp has fractional seconds
+    #[error("<{0}> has fractional seconds")]
+    FractionalSeconds(&'static str),
+}
+
+/// The error returned by `Alert::from_str_strict()`.
+#[derive(thiserror::Error, Debug)]
+pub enum StrictParseError {
+    /// The alert could not be parsed at all
+    #[error(transparent)]
+    Parse(#[from] crate::ParseError),
+    /// The alert was parsed, but does not conform to the CAP v1.2 specification
+    #[error("alert does not conform to CAP v1.2: {}", join(.0))]
+    Nonconforming(Vec<Conformance>),
+}
+
+/// The URL schemes which are generally safe to follow or render, for use with
+/// `Alert::validate_urls()`.
+pub const DEFAULT_URL_SCHEMES: &[&str] = &["http", "https", "ftp"];
+
+/// A URL whose scheme is not allowed, as returned by `Alert::validate_urls()`.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("<{path}> uses disallowed URL scheme {scheme:?}")]
+pub struct UrlIssue {
+    /// The path to the offending field, as accepted by `Alert::get_path()`, e.g. `info.0.web`
+    pub path: String,
+    /// The URL's scheme, e.g. `javascript`
+    pub scheme: String,
+}
+
+impl Alert {
+    /// Check this alert against rules in the CAP v1.2 specification which parsing does not
+    /// enforce, returning every violation found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let input = include_str!("../../fixtures/v1dot2_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.1 */;
+    /// let mut alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    /// assert_eq!(alert.validate(), Ok(()));
+    ///
+    /// alert.restriction = Some("Internal use only".into());
+    /// assert_eq!(
+    ///     alert.validate(),
+    ///     Err(vec![oasiscap::v1dot2::Conformance::ContradictoryScope("restriction")]),
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<Conformance>> {
+        let mut issues = Vec::new();
+
+        if self.scope == Scope::Public {
+            if self.restriction.is_some() {
+                issues.push(Conformance::ContradictoryScope("restriction"));
+            }
+            if self.addresses.is_some() {
+                issues.push(Conformance::ContradictoryScope("addresses"));
+            }
+        }
+
+        if self.references_required()
+            && self
+                .references
+                .as_ref()
+                .is_none_or(|references| references.as_slice().is_empty())
+        {
+            issues.push(Conformance::MissingElement("references"));
+        }
+
+        for info in &self.info {
+            if info.categories.is_empty() {
+                issues.push(Conformance::MissingElement("category"));
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// Returns `true` if this alert's `message_type` requires it to identify earlier messages in
+    /// `references`, i.e. for everything except `MessageType::Alert`.
+    ///
+    /// `validate()` reports `Conformance::MissingElement("references")` for such alerts which do
+    /// not.
+    pub fn references_required(&self) -> bool {
+        self.message_type != MessageType::Alert
+    }
+
+    /// Check that every `web` and resource `uri` URL in this alert uses one of `allowed_schemes`,
+    /// returning every URL which does not.
+    ///
+    /// URLs taken from an alert are untrusted input, and schemes like `file:` and `javascript:`
+    /// are dangerous to follow or render. Schemes are compared case-insensitively.
+    /// `DEFAULT_URL_SCHEMES` is a reasonable choice for `allowed_schemes`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let input = include_str!("../../fixtures/v1dot2_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.1 */;
+    /// use oasiscap::v1dot2::{Alert, UrlIssue, DEFAULT_URL_SCHEMES};
+    /// let mut alert: Alert = input.parse().unwrap();
+    /// assert_eq!(alert.validate_urls(DEFAULT_URL_SCHEMES), Ok(()));
+    ///
+    /// alert.info[0].web = Some("javascript:alert(1)".parse().unwrap());
+    /// assert_eq!(
+    ///     alert.validate_urls(DEFAULT_URL_SCHEMES),
+    ///     Err(vec![UrlIssue {
+    ///         path: "info.0.web".into(),
+    ///         scheme: "javascript".into(),
+    ///     }]),
+    /// );
+    /// ```
+    pub fn validate_urls(&self, allowed_schemes: &[&str]) -> Result<(), Vec<UrlIssue>> {
+        let mut issues = Vec::new();
+        let mut check = |path: String, url: &url::Url| {
+            if !allowed_schemes
+                .iter()
+                .any(|scheme| scheme.eq_ignore_ascii_case(url.scheme()))
+            {
+                issues.push(UrlIssue {
+                    path,
+                    scheme: url.scheme().to_string(),
+                });
+            }
+        };
+
+        for (i, info) in self.info.iter().enumerate() {
+            if let Some(url) = &info.web {
+                check(format!("info.{}.web", i), url);
+            }
+            for (j, resource) in info.resources.iter().enumerate() {
+                if let Some(url) = &resource.uri {
+                    check(format!("info.{}.resource.{}.uri", i, j), url);
+                }
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// Parse an alert, rejecting it unless it conforms to the CAP v1.2 specification.
+    ///
+    /// `str::parse()` accepts some non-conforming alerts which have an unambiguous meaning. This
+    /// parses in the same way, then fails if `validate()` finds any issues, or if the document
+    /// contains empty `<polygon>` elements, or timestamps with `Z` offsets or fractional seconds.
+    /// It is intended for conformance testing, where such alerts must be flagged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let input = include_str!("../../fixtures/v1dot2_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.1 */;
+    /// use oasiscap::v1dot2::{Alert, Conformance, StrictParseError};
+    /// assert!(Alert::from_str_strict(input).is_ok());
+    ///
+    /// let input = input.replace("-05:00</sent>", "Z</sent>");
+    /// assert!(input.parse::<Alert>().is_ok());
+    /// assert!(matches!(
+    ///     Alert::from_str_strict(&input),
+    ///     Err(StrictParseError::Nonconforming(issues))
+    ///         if issues == vec![Conformance::AlphabeticTimeZone("sent")]
+    /// ));
+    /// ```
+    pub fn from_str_strict(s: &str) -> Result<Self, StrictParseError> {
+        let alert: Alert = s.parse()?;
+
+        let mut issues = alert.validate().err().unwrap_or_default();
+        check_document(s, &mut issues);
+
+        if issues.is_empty() {
+            Ok(alert)
+        } else {
+            Err(StrictParseError::Nonconforming(issues))
+        }
+    }
+}
+
+fn join(issues: &[Conformance]) -> String {
+    issues
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Check the text of `xml`, a successfully parsed alert, for issues which parsing erases.
+fn check_document(xml: &str, issues: &mut Vec<Conformance>) {
+    let mut text = String::new();
+    for event in EventReader::new(xml.as_bytes()) {
+        match event {
+            Ok(XmlEvent::StartElement { .. }) => text.clear(),
+            Ok(XmlEvent::Characters(s)) | Ok(XmlEvent::CData(s)) => text.push_str(&s),
+            Ok(XmlEvent::EndElement { name }) => {
+                if name.namespace.as_deref() != Some(NAMESPACE) {
+                    continue;
+                }
+                let text = text.trim();
+                let timestamp = match name.local_name.as_str() {
+                    "polygon" if text.is_empty() => {
+                        issues.push(Conformance::EmptyPolygon);
+                        continue;
+                    }
+                    "sent" => "sent",
+                    "effective" => "effective",
+                    "onset" => "onset",
+                    "expires" => "expires",
+                    _ => continue,
+                };
+                if text.ends_with('Z') {
+                    issues.push(Conformance::AlphabeticTimeZone(timestamp));
+                }
+                if text.contains('.') {
+                    issues.push(Conformance::FractionalSeconds(timestamp));
+                }
+            }
+            Ok(_) => {}
+            // The document has already been parsed successfully
+            Err(_) => break,
+        }
+    }
+}
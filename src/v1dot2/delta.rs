@@ -0,0 +1,133 @@
+//! Differences between successive versions of an alert.
+//!
+//! Changes are described in terms of the dotted key-paths accepted by [`Alert::get_path`], so a
+//! consumer holding the previous alert can apply them without re-rendering everything.
+
+use super::{Alert, Info};
+use crate::id::Id;
+use crate::DateTime;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A single value which differs between two versions of an alert.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    /// The dotted key-path of the value, e.g. `info.0.headline`
+    pub path: String,
+    /// The previous value, or `None` if it was absent
+    pub previous: Option<String>,
+    /// The current value, or `None` if it was removed
+    pub current: Option<String>,
+}
+
+/// The changes between a previous alert and its successor, as produced by [`Alert::to_delta`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlertDelta {
+    /// The sender of the current alert
+    pub sender: Id,
+    /// The identifier of the current alert
+    pub identifier: Id,
+    /// The time the current alert was sent
+    pub sent: DateTime,
+    /// The identifier of the previous alert
+    pub previous_identifier: Id,
+    /// The time the previous alert was sent
+    pub previous_sent: DateTime,
+    /// Every value which was added, removed, or modified, in document order
+    pub changes: Vec<FieldChange>,
+}
+
+impl AlertDelta {
+    /// Returns `true` if the two alerts carried the same content.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl Alert {
+    /// Describe what changed in this alert since `previous`.
+    ///
+    /// `identifier` and `sent` are expected to differ between messages and are reported as part of
+    /// the delta itself rather than as changes. Any other difference, including a change of
+    /// `sender`, is reported as a change.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let input = include_str!("../../fixtures/v1dot2_appendix_adot3.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.3 */;
+    /// let previous: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    /// let mut current = previous.clone();
+    /// current.identifier = "TRI13970876.3".parse().unwrap();
+    /// current.info[0].headline = Some("EQ 3.5 Imperial County CA".into());
+    ///
+    /// let delta = current.to_delta(&previous);
+    /// assert_eq!(delta.changes.len(), 1);
+    /// assert_eq!(delta.changes[0].path, "info.0.headline");
+    /// assert_eq!(delta.changes[0].current.as_deref(), Some("EQ 3.5 Imperial County CA"));
+    /// ```
+    pub fn to_delta(&self, previous: &Alert) -> AlertDelta {
+        AlertDelta {
+            sender: self.sender.clone(),
+            identifier: self.identifier.clone(),
+            sent: self.sent,
+            previous_identifier: previous.identifier.clone(),
+            previous_sent: previous.sent,
+            changes: diff(&delta_fields(previous), &delta_fields(self)),
+        }
+    }
+}
+
+impl Info {
+    /// Describe what changed in this `Info` since `previous`.
+    ///
+    /// Paths are relative to the `Info`, e.g. `headline` or `area.0.areaDesc`.
+    pub fn diff(&self, previous: &Info) -> Vec<FieldChange> {
+        diff(&previous.fields(), &self.fields())
+    }
+}
+
+/// The fields of `alert` which are compared by `to_delta()`.
+fn delta_fields(alert: &Alert) -> Vec<(String, String)> {
+    let mut fields = alert.fields();
+    fields.retain(|(path, _)| path != "identifier" && path != "sent");
+    fields
+}
+
+fn diff(previous: &[(String, String)], current: &[(String, String)]) -> Vec<FieldChange> {
+    let previous_by_path: HashMap<&str, &str> = previous
+        .iter()
+        .map(|(path, value)| (path.as_str(), value.as_str()))
+        .collect();
+    let current_by_path: HashMap<&str, &str> = current
+        .iter()
+        .map(|(path, value)| (path.as_str(), value.as_str()))
+        .collect();
+
+    // Additions and modifications, in the order of the current alert
+    let mut changes: Vec<FieldChange> = current
+        .iter()
+        .filter_map(|(path, value)| match previous_by_path.get(path.as_str()) {
+            Some(previous) if *previous == value.as_str() => None,
+            previous => Some(FieldChange {
+                path: path.clone(),
+                previous: previous.map(|v| v.to_string()),
+                current: Some(value.clone()),
+            }),
+        })
+        .collect();
+
+    // Removals, in the order of the previous alert
+    changes.extend(
+        previous
+            .iter()
+            .filter(|(path, _)| !current_by_path.contains_key(path.as_str()))
+            .map(|(path, value)| FieldChange {
+                path: path.clone(),
+                previous: Some(value.clone()),
+                current: None,
+            }),
+    );
+
+    changes
+}
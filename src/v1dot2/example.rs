@@ -0,0 +1,110 @@
+//! Example alerts, for use in tests.
+
+use super::{
+    Alert, Area, Category, Certainty, Info, Map, MessageType, Scope, Severity, Status, Urgency,
+};
+use crate::references::{Reference, References};
+
+impl Alert {
+    /// A consistent chain of three alerts concerning the same event: an initial `Alert`, an
+    /// `Update` which references it, and a `Cancel` which references both.
+    ///
+    /// This is intended to reduce boilerplate in tests which exercise message sequencing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oasiscap::v1dot2::{Alert, MessageType};
+    ///
+    /// let chain = Alert::chain_example();
+    /// assert_eq!(
+    ///     chain.iter().map(|alert| alert.message_type).collect::<Vec<_>>(),
+    ///     vec![MessageType::Alert, MessageType::Update, MessageType::Cancel],
+    /// );
+    ///
+    /// let cancel = &chain[2];
+    /// let references = cancel.references.as_ref().unwrap();
+    /// assert_eq!(references.len(), 2);
+    /// assert_eq!(references[0].identifier, chain[0].identifier);
+    /// assert_eq!(references[1].identifier, chain[1].identifier);
+    /// ```
+    pub fn chain_example() -> Vec<Alert> {
+        let initial = Alert {
+            identifier: "EXAMPLE-1".parse().unwrap(),
+            sender: "alerts@example.com".parse().unwrap(),
+            sent: "2003-06-11T20:30:00-07:00".parse().unwrap(),
+            status: Status::Actual,
+            message_type: MessageType::Alert,
+            source: None,
+            scope: Scope::Public,
+            restriction: None,
+            addresses: None,
+            codes: Vec::new(),
+            note: None,
+            references: None,
+            incidents: None,
+            info: vec![Info {
+                language: Default::default(),
+                categories: vec![Category::Geo],
+                event: "Earthquake".into(),
+                response_type: Vec::new(),
+                urgency: Urgency::Past,
+                severity: Severity::Minor,
+                certainty: Certainty::Observed,
+                audience: None,
+                event_codes: Map::new(),
+                effective: None,
+                onset: None,
+                expires: None,
+                sender_name: Some("Example Seismic Network".into()),
+                headline: Some("EQ 3.4 Imperial County CA".into()),
+                description: Some(
+                    "A minor earthquake measuring 3.4 on the Richter scale occurred near Brawley, \
+                    California."
+                        .into(),
+                ),
+                instruction: None,
+                web: None,
+                contact: None,
+                parameters: Map::new(),
+                resources: Vec::new(),
+                areas: vec![Area {
+                    description: "1 mi. WSW of Brawley, CA".into(),
+                    polygons: Vec::new(),
+                    circles: vec!["32.9525,-115.5527 0".parse().unwrap()],
+                    geocode: Map::new(),
+                    altitude: None,
+                    ceiling: None,
+                }],
+            }],
+        };
+
+        let mut update = initial.clone();
+        update.identifier = "EXAMPLE-2".parse().unwrap();
+        update.sent = "2003-06-11T20:56:00-07:00".parse().unwrap();
+        update.message_type = MessageType::Update;
+        update.references = Some(References::new(vec![reference(&initial)]));
+        update.info[0].headline = Some("EQ 3.5 Imperial County CA".into());
+
+        let mut cancel = update.clone();
+        cancel.identifier = "EXAMPLE-3".parse().unwrap();
+        cancel.sent = "2003-06-11T21:15:00-07:00".parse().unwrap();
+        cancel.message_type = MessageType::Cancel;
+        cancel.note = Some("Event was a quarry blast".into());
+        cancel.references = Some(References::new(vec![
+            reference(&initial),
+            reference(&update),
+        ]));
+        cancel.info.clear();
+
+        vec![initial, update, cancel]
+    }
+}
+
+fn reference(alert: &Alert) -> Reference {
+    Reference {
+        sender: alert.sender.clone(),
+        identifier: alert.identifier.clone(),
+        sent: alert.sent,
+    }
+}
@@ -0,0 +1,316 @@
+//! Preserving elements which are not part of CAP v1.2 across parsing and serialization.
+//!
+//! Extensions are stored as (qualified name, inner XML) pairs. Qualified names use Clark notation,
+//! i.e. `{namespace}localName`, or just `localName` for elements without a namespace. Attributes
+//! on the extension element itself are not retained. Inner XML is normalized as it is captured:
+//! whitespace between child elements is dropped, and each child element declares the namespaces
+//! it uses. This makes capturing an extension, emitting it, and capturing it again produce the same
+//! pair, whichever namespace prefixes the documents used.
+//!
+//! Capturing and emitting extensions each take an extra pass over the document, so both are
+//! opt-in: `str::parse()` and `to_string()` ignore extensions entirely.
+
+use super::{Alert, AlertDocument};
+use std::borrow::Cow;
+use xml::name::OwnedName;
+use xml::namespace::{Namespace, NS_NO_PREFIX};
+use xml::reader::{EventReader, XmlEvent};
+use xml::writer::{EmitterConfig, EventWriter};
+
+const NAMESPACE: &str = "urn:oasis:names:tc:emergency:cap:1.2";
+
+const ALERT_ELEMENTS: &[&str] = &[
+    "identifier",
+    "sender",
+    "sent",
+    "status",
+    "msgType",
+    "source",
+    "scope",
+    "restriction",
+    "addresses",
+    "code",
+    "note",
+    "references",
+    "incidents",
+    "info",
+];
+
+const INFO_ELEMENTS: &[&str] = &[
+    "language",
+    "category",
+    "event",
+    "responseType",
+    "urgency",
+    "severity",
+    "certainty",
+    "audience",
+    "eventCode",
+    "effective",
+    "onset",
+    "expires",
+    "senderName",
+    "headline",
+    "description",
+    "instruction",
+    "web",
+    "contact",
+    "parameter",
+    "resource",
+    "area",
+];
+
+/// Elements which are not part of CAP v1.2, as captured by
+/// `Alert::from_str_preserving_extensions()`.
+///
+/// Each extension is a (qualified name, inner XML) pair, where the qualified name is written as
+/// `{namespace}localName`.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Extensions {
+    /// Child elements of `<alert>`, which are emitted after the last `<info>`
+    pub alert: Vec<(String, String)>,
+
+    /// Child elements of each `<info>`, which are emitted at the end of the corresponding `<info>`
+    ///
+    /// This has one entry per `Alert::info`, in the same order.
+    pub info: Vec<Vec<(String, String)>>,
+}
+
+impl Extensions {
+    /// Returns `true` if there are no extensions at all.
+    pub fn is_empty(&self) -> bool {
+        self.alert.is_empty() && self.info.iter().all(Vec::is_empty)
+    }
+}
+
+impl Alert {
+    /// Parse an alert, also capturing any child elements of `<alert>` and `<info>` which are not
+    /// part of CAP v1.2.
+    ///
+    /// `str::parse()` discards such elements. Relays which need to forward alerts they don't fully
+    /// understand can use this instead, then write the alert with `write_xml_with_extensions()`.
+    /// This reads the document twice, so it's slower than `str::parse()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let input = include_str!("../../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// use oasiscap::v1dot2::Alert;
+    ///
+    /// let input = input.replacen(
+    ///     "</info>",
+    ///     "<originatorPIN xmlns=\"urn:example\">1234</originatorPIN></info>",
+    ///     1,
+    /// );
+    /// let (alert, extensions) = Alert::from_str_preserving_extensions(&input).unwrap();
+    /// assert_eq!(
+    ///     extensions.info[0],
+    ///     vec![("{urn:example}originatorPIN".to_string(), "1234".to_string())]
+    /// );
+    ///
+    /// let mut output = Vec::new();
+    /// alert.write_xml_with_extensions(&extensions, &mut output).unwrap();
+    /// let output = String::from_utf8(output).unwrap();
+    /// assert!(output.contains(r#"<originatorPIN xmlns="urn:example">1234</originatorPIN>"#));
+    /// ```
+    pub fn from_str_preserving_extensions(
+        s: &str,
+    ) -> Result<(Self, Extensions), crate::ParseError> {
+        let alert: Alert = s.parse()?;
+        let extensions = capture(s).map_err(|e| {
+            crate::ParseError::new(s, <xml_serde::Error as serde::de::Error>::custom(e))
+        })?;
+        Ok((alert, extensions))
+    }
+
+    /// Write this alert as XML to `w`, inserting `extensions`.
+    ///
+    /// Without extensions, this produces the same output as `write_xml()`. Otherwise, the
+    /// serialized alert is read again to insert them, so this is slower.
+    pub fn write_xml_with_extensions<W: std::io::Write>(
+        &self,
+        extensions: &Extensions,
+        mut w: W,
+    ) -> Result<(), crate::Error> {
+        if extensions.is_empty() {
+            return self.write_xml(w);
+        }
+
+        let xml = crate::parse_error::to_string(&AlertDocument { alert: self })?;
+        let xml = emit(&xml, extensions).map_err(|e| crate::Error::Serialize(e.into()))?;
+        w.write_all(xml.as_bytes())
+            .map_err(|e| crate::Error::Serialize(e.into()))
+    }
+}
+
+/// The error returned when extensions can't be captured or emitted.
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("error reading XML: {0}")]
+    Read(#[from] xml::reader::Error),
+    #[error("error writing XML: {0}")]
+    Write(#[from] xml::writer::Error),
+    #[error(transparent)]
+    Utf8(#[from] std::string::FromUtf8Error),
+}
+
+type Pairs = Vec<(String, String)>;
+
+/// Insert `extensions` into `xml`, a serialized alert.
+fn emit(xml: &str, extensions: &Extensions) -> Result<String, Error> {
+    let mut writer = EventWriter::new_with_config(
+        Vec::new(),
+        EmitterConfig::new().normalize_empty_elements(false),
+    );
+    let mut depth = 0usize;
+    let mut info_index = 0usize;
+    for event in EventReader::new(xml.as_bytes()) {
+        let event = event?;
+        match &event {
+            XmlEvent::StartElement { .. } => depth += 1,
+            XmlEvent::EndElement { name } => {
+                match depth {
+                    1 => write_extensions(&mut writer, &extensions.alert)?,
+                    2 if is_cap(name, "info") => {
+                        if let Some(info) = extensions.info.get(info_index) {
+                            write_extensions(&mut writer, info)?;
+                        }
+                        info_index += 1;
+                    }
+                    _ => {}
+                }
+                depth -= 1;
+            }
+            _ => {}
+        }
+        write(&mut writer, &event)?;
+    }
+
+    Ok(String::from_utf8(writer.into_inner())?)
+}
+
+/// Find the extensions of the root `<alert>` element and of each of its `<info>` elements.
+fn capture(xml: &str) -> Result<Extensions, Error> {
+    let mut reader = EventReader::new(xml.as_bytes());
+    let mut extensions = Extensions::default();
+    let mut depth = 0usize;
+    loop {
+        match reader.next()? {
+            XmlEvent::StartElement { name, .. } => {
+                depth += 1;
+                let pairs: Option<&mut Pairs> = match depth {
+                    2 if is_cap(&name, "info") => {
+                        extensions.info.push(Vec::new());
+                        None
+                    }
+                    2 if !is_known(&name, ALERT_ELEMENTS) => Some(&mut extensions.alert),
+                    3 if !is_known(&name, INFO_ELEMENTS) => extensions.info.last_mut(),
+                    _ => None,
+                };
+                if let Some(pairs) = pairs {
+                    pairs.push((qualified_name(&name), read_inner(&mut reader)?));
+                    depth -= 1;
+                }
+            }
+            XmlEvent::EndElement { .. } => depth -= 1,
+            XmlEvent::EndDocument => return Ok(extensions),
+            _ => {}
+        }
+    }
+}
+
+fn is_cap(name: &OwnedName, local_name: &str) -> bool {
+    name.namespace.as_deref() == Some(NAMESPACE) && name.local_name == local_name
+}
+
+fn is_known(name: &OwnedName, local_names: &[&str]) -> bool {
+    name.namespace.as_deref() == Some(NAMESPACE) && local_names.contains(&name.local_name.as_str())
+}
+
+fn qualified_name(name: &OwnedName) -> String {
+    match &name.namespace {
+        Some(namespace) => format!("{{{}}}{}", namespace, name.local_name),
+        None => name.local_name.clone(),
+    }
+}
+
+/// Read the content of an element whose `StartElement` has just been read, returning it as XML.
+fn read_inner<R: std::io::Read>(reader: &mut EventReader<R>) -> Result<String, Error> {
+    let mut writer = EventWriter::new_with_config(
+        Vec::new(),
+        EmitterConfig::new()
+            .write_document_declaration(false)
+            .normalize_empty_elements(false),
+    );
+
+    let mut depth = 1usize;
+    loop {
+        let event = reader.next()?;
+        match event {
+            XmlEvent::StartElement {
+                name,
+                attributes,
+                namespace: _,
+            } => {
+                depth += 1;
+                // Declare only the namespaces this element actually uses
+                let mut namespace = Namespace::empty();
+                for name in std::iter::once(&name).chain(attributes.iter().map(|attr| &attr.name)) {
+                    if let Some(uri) = &name.namespace {
+                        namespace.put(name.prefix.as_deref().unwrap_or(NS_NO_PREFIX), uri.as_str());
+                    }
+                }
+                writer.write(xml::writer::XmlEvent::StartElement {
+                    name: name.borrow(),
+                    attributes: attributes.iter().map(|attr| attr.borrow()).collect(),
+                    namespace: Cow::Owned(namespace),
+                })?;
+                continue;
+            }
+            XmlEvent::EndElement { .. } => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            XmlEvent::Whitespace(_) => continue,
+            _ => {}
+        }
+        write(&mut writer, &event)?;
+    }
+
+    Ok(String::from_utf8(writer.into_inner())?)
+}
+
+/// Write each extension as an element named by its qualified name, containing its inner XML.
+fn write_extensions(
+    writer: &mut EventWriter<Vec<u8>>,
+    extensions: &[(String, String)],
+) -> Result<(), Error> {
+    for (name, inner) in extensions {
+        let element = match name.strip_prefix('{').and_then(|name| name.split_once('}')) {
+            Some((namespace, local_name)) => format!(
+                "<{0} xmlns=\"{1}\">{2}</{0}>",
+                local_name,
+                xml::escape::escape_str_attribute(namespace),
+                inner
+            ),
+            None => format!("<{0}>{1}</{0}>", name, inner),
+        };
+        for event in EventReader::new(element.as_bytes()) {
+            match event? {
+                XmlEvent::StartDocument { .. } => {}
+                event => write(writer, &event)?,
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write(writer: &mut EventWriter<Vec<u8>>, event: &XmlEvent) -> Result<(), Error> {
+    if let Some(writer_event) = event.as_writer_event() {
+        writer.write(writer_event)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,116 @@
+//! Converting the affected areas of an alert to GeoJSON.
+
+use super::{Alert, Area, Info};
+use ::geojson::{Feature, FeatureCollection, Geometry, JsonObject, JsonValue, Value};
+
+impl Alert {
+    /// Describe the affected areas of this alert as a GeoJSON `FeatureCollection`, suitable for
+    /// displaying as a map layer.
+    ///
+    /// Each `Area` of each `Info` contributes, in order:
+    ///
+    /// 1. One feature whose geometry is a `MultiPolygon` containing the area's polygons, or no
+    ///    geometry at all if the area has no polygons and no circles (e.g. if it only has
+    ///    geocodes).
+    /// 2. One feature per circle, whose geometry is a `Point` at the circle's center, with the
+    ///    circle's radius in kilometers as a `radius` property.
+    ///
+    /// Every feature has `event`, `severity`, and `language` properties taken from its `Info`, and
+    /// an `areaDesc` property taken from its `Area`. Positions are written as longitude, latitude,
+    /// as GeoJSON requires. Requires the `geojson` feature.
+    ///
+    /// ```
+    /// # let input = include_str!("../../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    ///
+    /// let collection = alert.to_geojson();
+    /// assert_eq!(collection.features.len(), 1);
+    ///
+    /// let feature = &collection.features[0];
+    /// assert_eq!(feature.property("event").unwrap(), "SEVERE THUNDERSTORM");
+    /// assert_eq!(feature.property("severity").unwrap(), "Severe");
+    /// assert_eq!(feature.property("language").unwrap(), "en-US");
+    ///
+    /// // The first point of the first polygon is 38.47,-120.14
+    /// match &feature.geometry.as_ref().unwrap().value {
+    ///     geojson::Value::MultiPolygon(polygons) => assert_eq!(polygons[0][0][0], vec![-120.14, 38.47]),
+    ///     other => panic!("unexpected geometry: {:?}", other),
+    /// }
+    /// ```
+    pub fn to_geojson(&self) -> FeatureCollection {
+        let features = self
+            .info
+            .iter()
+            .flat_map(|info| {
+                info.areas
+                    .iter()
+                    .flat_map(move |area| area_features(info, area))
+            })
+            .collect();
+
+        FeatureCollection {
+            bbox: None,
+            features,
+            foreign_members: None,
+        }
+    }
+}
+
+fn area_features(info: &Info, area: &Area) -> Vec<Feature> {
+    let properties = {
+        let mut properties = JsonObject::new();
+        properties.insert("event".into(), JsonValue::from(info.event.as_str()));
+        properties.insert(
+            "severity".into(),
+            JsonValue::from(info.severity.to_string()),
+        );
+        properties.insert("language".into(), JsonValue::from(info.language.as_str()));
+        properties.insert(
+            "areaDesc".into(),
+            JsonValue::from(area.description.as_str()),
+        );
+        properties
+    };
+    let feature = |geometry: Option<Value>, properties: JsonObject| Feature {
+        bbox: None,
+        geometry: geometry.map(Geometry::new),
+        id: None,
+        properties: Some(properties),
+        foreign_members: None,
+    };
+
+    let mut features = Vec::new();
+    if !area.polygons.is_empty() || area.circles.is_empty() {
+        let geometry = if area.polygons.is_empty() {
+            None
+        } else {
+            Some(Value::MultiPolygon(
+                area.polygons
+                    .iter()
+                    .map(|polygon| {
+                        vec![polygon
+                            .iter()
+                            .map(|point| vec![point.longitude(), point.latitude()])
+                            .collect()]
+                    })
+                    .collect(),
+            ))
+        };
+        features.push(feature(geometry, properties.clone()));
+    }
+
+    for circle in &area.circles {
+        let mut properties = properties.clone();
+        properties.insert("radius".into(), JsonValue::from(circle.radius));
+        features.push(feature(
+            Some(Value::Point(vec![
+                circle.center.longitude(),
+                circle.center.latitude(),
+            ])),
+            properties,
+        ));
+    }
+
+    features
+}
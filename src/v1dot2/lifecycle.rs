@@ -0,0 +1,116 @@
+//! Composing messages which respond to an earlier alert.
+
+use super::{Alert, MessageType};
+use crate::id::Id;
+use crate::references::{Reference, References};
+
+impl Alert {
+    /// Compose an `Ack` message acknowledging receipt and acceptance of `original`.
+    ///
+    /// The acknowledgement is sent now by `sender`, and references `original`. It has the same
+    /// `status` and `scope` as `original`, along with any `restriction` or `addresses` which that
+    /// scope requires, and no `Info` blocks.
+    ///
+    /// ```
+    /// # let input = include_str!("../../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// use oasiscap::v1dot2::{Alert, MessageType};
+    /// let original: oasiscap::Alert = input.parse().unwrap();
+    ///
+    /// let ack = Alert::acknowledge(
+    ///     &original,
+    ///     "ops@example.com".parse().unwrap(),
+    ///     "ACK-1".parse().unwrap(),
+    /// );
+    /// assert_eq!(ack.message_type, MessageType::Ack);
+    /// assert!(ack.info.is_empty());
+    ///
+    /// let references = ack.references.as_ref().unwrap();
+    /// assert_eq!(references.len(), 1);
+    /// assert_eq!(&references[0].sender, original.sender());
+    /// assert_eq!(&references[0].identifier, original.identifier());
+    /// assert_eq!(references[0].sent, original.sent());
+    /// ```
+    pub fn acknowledge(original: &crate::Alert, sender: Id, identifier: Id) -> Alert {
+        let original = original.clone().into_latest();
+        let references = References::new(vec![reference(&original)]);
+        Alert {
+            identifier,
+            sender,
+            sent: crate::DateTime::now(),
+            status: original.status,
+            message_type: MessageType::Ack,
+            source: None,
+            scope: original.scope,
+            restriction: original.restriction,
+            addresses: original.addresses,
+            codes: Vec::new(),
+            note: None,
+            references: Some(references),
+            incidents: None,
+            info: Vec::new(),
+        }
+    }
+
+    /// Compose a `Cancel` message cancelling `original`, explaining why in `note`.
+    ///
+    /// The cancellation is sent now by `sender`, and references `original`. It has the same
+    /// `status`, `scope`, `restriction`, `addresses`, and `incidents` as `original`. It also
+    /// carries the `Info` blocks of `original`, so that recipients can match it to the same
+    /// audiences and areas.
+    ///
+    /// ```
+    /// # let input = include_str!("../../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// use oasiscap::v1dot2::{Alert, MessageType};
+    /// let original: oasiscap::Alert = input.parse().unwrap();
+    ///
+    /// let cancel = Alert::cancel(
+    ///     &original,
+    ///     "w-nws.webmaster@noaa.gov".parse().unwrap(),
+    ///     "KSTO1055887204".parse().unwrap(),
+    ///     "The storm has weakened",
+    /// );
+    /// assert_eq!(cancel.message_type, MessageType::Cancel);
+    /// assert_eq!(cancel.note.as_deref(), Some("The storm has weakened"));
+    /// assert_eq!(cancel.info[0].areas, original.into_latest().info[0].areas);
+    ///
+    /// let references = cancel.references.as_ref().unwrap();
+    /// assert_eq!(references.len(), 1);
+    /// assert_eq!(references[0].identifier, "KSTO1055887203");
+    /// ```
+    pub fn cancel<N: Into<String>>(
+        original: &crate::Alert,
+        sender: Id,
+        identifier: Id,
+        note: N,
+    ) -> Alert {
+        let original = original.clone().into_latest();
+        let references = References::new(vec![reference(&original)]);
+        Alert {
+            identifier,
+            sender,
+            sent: crate::DateTime::now(),
+            status: original.status,
+            message_type: MessageType::Cancel,
+            source: None,
+            scope: original.scope,
+            restriction: original.restriction,
+            addresses: original.addresses,
+            codes: Vec::new(),
+            note: Some(note.into()),
+            references: Some(references),
+            incidents: original.incidents,
+            info: original.info,
+        }
+    }
+}
+
+/// A `Reference` to `alert`.
+fn reference(alert: &Alert) -> Reference {
+    Reference {
+        sender: alert.sender.clone(),
+        identifier: alert.identifier.clone(),
+        sent: alert.sent,
+    }
+}
@@ -0,0 +1,107 @@
+//! Merging the geometry of an `Info`'s areas, using the `geo` crate.
+
+use super::Info;
+use crate::geo::{Circle, Point, Polygon, EARTH_RADIUS_KM};
+use ::geo::BooleanOps;
+
+/// The number of points used to approximate each circle.
+const CIRCLE_SEGMENTS: usize = 64;
+
+impl Info {
+    /// Compute the union of every polygon and circle in this `Info`'s areas.
+    ///
+    /// Alerts frequently describe a region as many small adjacent or overlapping areas, which is
+    /// awkward to display. This merges them into as few polygons as possible. Circles are
+    /// approximated by 64-sided polygons, and circles with a radius of zero are ignored.
+    ///
+    /// The union is computed in the plane of latitude and longitude, so results are not meaningful
+    /// for areas which cross the antimeridian. CAP polygons cannot have holes, so any holes in the
+    /// union are filled in. Requires the `geo` feature.
+    ///
+    /// ```
+    /// # let input = include_str!("../../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    /// let mut info = alert.info[0].clone();
+    ///
+    /// // Duplicating an area doesn't change the union
+    /// info.areas.push(info.areas[0].clone());
+    /// let merged = info.merged_area_geometry();
+    /// assert_eq!(merged.len(), 1);
+    /// assert_eq!(merged[0].iter().count(), 5);
+    /// ```
+    pub fn merged_area_geometry(&self) -> Vec<Polygon> {
+        let polygons = self
+            .areas
+            .iter()
+            .flat_map(|area| {
+                area.polygons.iter().cloned().chain(
+                    area.circles
+                        .iter()
+                        .filter(|circle| circle.radius > 0.0)
+                        .map(approximate_circle),
+                )
+            })
+            .map(|polygon| {
+                ::geo::MultiPolygon(vec![::geo::Polygon::new(
+                    polygon
+                        .iter()
+                        .map(|point| (point.longitude(), point.latitude()))
+                        .collect::<Vec<_>>()
+                        .into(),
+                    Vec::new(),
+                )])
+            });
+
+        let mut union = ::geo::MultiPolygon(Vec::new());
+        for polygon in polygons {
+            union = union.union(&polygon);
+        }
+
+        union
+            .0
+            .into_iter()
+            .filter_map(|polygon| {
+                let points = polygon
+                    .exterior()
+                    .points()
+                    .map(|point| {
+                        Point::new(point.y().clamp(-90.0, 90.0), point.x().clamp(-180.0, 180.0))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .ok()?;
+                // Degenerate slivers may have too few points to be a CAP polygon
+                Polygon::try_from(points).ok()
+            })
+            .collect()
+    }
+}
+
+/// Approximate `circle` with a polygon, placing points around it on a spherical Earth.
+fn approximate_circle(circle: &Circle) -> Polygon {
+    let latitude = circle.center.latitude().to_radians();
+    let longitude = circle.center.longitude().to_radians();
+    let angle = circle.radius / EARTH_RADIUS_KM;
+
+    let mut points = (0..CIRCLE_SEGMENTS)
+        .map(|i| {
+            let bearing = std::f64::consts::TAU * i as f64 / CIRCLE_SEGMENTS as f64;
+            let point_latitude = (latitude.sin() * angle.cos()
+                + latitude.cos() * angle.sin() * bearing.cos())
+            .asin();
+            let point_longitude = longitude
+                + (bearing.sin() * angle.sin() * latitude.cos())
+                    .atan2(angle.cos() - latitude.sin() * point_latitude.sin());
+            // Wrap the longitude into [-180, 180]
+            let point_longitude = (point_longitude.to_degrees() + 540.0).rem_euclid(360.0) - 180.0;
+            Point::new(
+                point_latitude.to_degrees().clamp(-90.0, 90.0),
+                point_longitude,
+            )
+            .expect("coordinates are in range")
+        })
+        .collect::<Vec<_>>();
+    points.push(points[0]);
+
+    Polygon::try_from(points).expect("circle approximation is a valid polygon")
+}
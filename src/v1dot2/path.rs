@@ -0,0 +1,161 @@
+//! Dotted key-path access, for binding alerts to templating engines.
+//!
+//! Path segments are CAP element names, and repeated elements are addressed by a zero-based index,
+//! e.g. `info.0.area.0.areaDesc`. Map entries are addressed by index as well, followed by
+//! `valueName` or `value`, e.g. `info.0.parameter.0.value`.
+
+use super::{Alert, Area, Info, Map, Resource};
+
+impl Alert {
+    /// Resolve a dotted key-path like `info.0.headline` to the string value at that location.
+    ///
+    /// Returns `None` if the path does not name an element, or if that element is absent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let input = include_str!("../../fixtures/v1dot2_appendix_adot1.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.1 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    ///
+    /// assert_eq!(
+    ///     alert.get_path("info.0.headline").as_deref(),
+    ///     Some("Homeland Security Sets Code ORANGE"),
+    /// );
+    /// assert_eq!(
+    ///     alert.get_path("info.0.area.0.areaDesc").as_deref(),
+    ///     Some("U.S. nationwide and interests worldwide"),
+    /// );
+    /// assert_eq!(alert.get_path("info.0.parameter.0.value").as_deref(), Some("ORANGE"));
+    ///
+    /// // Absent elements and unknown paths resolve to `None`
+    /// assert_eq!(alert.get_path("note"), None);
+    /// assert_eq!(alert.get_path("info.1.headline"), None);
+    /// assert_eq!(alert.get_path("info.0.headline.extra"), None);
+    /// ```
+    pub fn get_path(&self, path: &str) -> Option<String> {
+        self.fields()
+            .into_iter()
+            .find_map(|(field, value)| (field == path).then_some(value))
+    }
+
+    /// Every value present in this alert, keyed by path, in document order.
+    pub(super) fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = Fields::default();
+        fields.push("identifier", Some(self.identifier.to_string()));
+        fields.push("sender", Some(self.sender.to_string()));
+        fields.push("sent", Some(self.sent.to_string()));
+        fields.push("status", Some(self.status.to_string()));
+        fields.push("msgType", Some(self.message_type.to_string()));
+        fields.push("source", self.source.clone());
+        fields.push("scope", Some(self.scope.to_string()));
+        fields.push("restriction", self.restriction.clone());
+        fields.push("addresses", self.addresses.as_ref().map(|v| v.to_string()));
+        fields.push_list("code", self.codes.iter());
+        fields.push("note", self.note.clone());
+        fields.push(
+            "references",
+            self.references.as_ref().map(|v| v.to_string()),
+        );
+        fields.push("incidents", self.incidents.as_ref().map(|v| v.to_string()));
+        for (i, info) in self.info.iter().enumerate() {
+            fields.nest(&format!("info.{}", i), info.fields());
+        }
+        fields.0
+    }
+}
+
+impl Info {
+    /// Every value present in this `Info`, keyed by path relative to it, in document order.
+    pub(super) fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = Fields::default();
+        fields.push("language", Some(self.language.as_str().to_string()));
+        fields.push_list("category", self.categories.iter());
+        fields.push("event", Some(self.event.clone()));
+        fields.push_list("responseType", self.response_type.iter());
+        fields.push("urgency", Some(self.urgency.to_string()));
+        fields.push("severity", Some(self.severity.to_string()));
+        fields.push("certainty", Some(self.certainty.to_string()));
+        fields.push("audience", self.audience.clone());
+        fields.push_map("eventCode", &self.event_codes);
+        fields.push("effective", self.effective.map(|v| v.to_string()));
+        fields.push("onset", self.onset.map(|v| v.to_string()));
+        fields.push("expires", self.expires.map(|v| v.to_string()));
+        fields.push("senderName", self.sender_name.clone());
+        fields.push("headline", self.headline.clone());
+        fields.push("description", self.description.clone());
+        fields.push("instruction", self.instruction.clone());
+        fields.push("web", self.web.as_ref().map(|v| v.to_string()));
+        fields.push("contact", self.contact.clone());
+        fields.push_map("parameter", &self.parameters);
+        for (i, resource) in self.resources.iter().enumerate() {
+            fields.nest(&format!("resource.{}", i), resource.fields());
+        }
+        for (i, area) in self.areas.iter().enumerate() {
+            fields.nest(&format!("area.{}", i), area.fields());
+        }
+        fields.0
+    }
+}
+
+impl Resource {
+    fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = Fields::default();
+        fields.push("resourceDesc", Some(self.description.clone()));
+        fields.push("mimeType", Some(self.mime_type.clone()));
+        fields.push("size", self.size.map(|v| v.to_string()));
+        fields.push("uri", self.uri.as_ref().map(|v| v.to_string()));
+        fields.push(
+            "derefUri",
+            self.embedded_content.as_ref().map(|v| v.to_string()),
+        );
+        fields.push("digest", self.digest.map(|v| v.to_string()));
+        fields.0
+    }
+}
+
+impl Area {
+    fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = Fields::default();
+        fields.push("areaDesc", Some(self.description.clone()));
+        fields.push_list("polygon", self.polygons.iter());
+        fields.push_list("circle", self.circles.iter());
+        fields.push_map("geocode", &self.geocode);
+        fields.push("altitude", self.altitude.map(|v| v.to_string()));
+        fields.push("ceiling", self.ceiling.map(|v| v.to_string()));
+        fields.0
+    }
+}
+
+/// Every present value, keyed by path, in document order.
+#[derive(Default)]
+struct Fields(Vec<(String, String)>);
+
+impl Fields {
+    fn push(&mut self, path: &str, value: Option<String>) {
+        if let Some(value) = value {
+            self.0.push((path.to_string(), value));
+        }
+    }
+
+    fn push_list<T: ToString>(&mut self, path: &str, values: impl Iterator<Item = T>) {
+        for (i, value) in values.enumerate() {
+            self.0.push((format!("{}.{}", path, i), value.to_string()));
+        }
+    }
+
+    fn push_map(&mut self, path: &str, map: &Map) {
+        for (i, (value_name, value)) in map.iter().enumerate() {
+            self.0
+                .push((format!("{}.{}.valueName", path, i), value_name.to_string()));
+            self.0
+                .push((format!("{}.{}.value", path, i), value.to_string()));
+        }
+    }
+
+    fn nest(&mut self, prefix: &str, fields: Vec<(String, String)>) {
+        for (path, value) in fields {
+            self.0.push((format!("{}.{}", prefix, path), value));
+        }
+    }
+}
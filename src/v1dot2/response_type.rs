@@ -23,6 +23,61 @@ pub enum ResponseType {
     None,
 }
 
+impl ResponseType {
+    /// Every `ResponseType`, in the order listed by the specification.
+    pub const ALL: &[ResponseType] = &[
+        ResponseType::Shelter,
+        ResponseType::Evacuate,
+        ResponseType::Prepare,
+        ResponseType::Execute,
+        ResponseType::Avoid,
+        ResponseType::Monitor,
+        ResponseType::Assess,
+        ResponseType::AllClear,
+        ResponseType::None,
+    ];
+
+    /// Returns the name of the `ResponseType` as a `&str`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ResponseType::Shelter => "Shelter",
+            ResponseType::Evacuate => "Evacuate",
+            ResponseType::Prepare => "Prepare",
+            ResponseType::Execute => "Execute",
+            ResponseType::Avoid => "Avoid",
+            ResponseType::Monitor => "Monitor",
+            ResponseType::Assess => "Assess",
+            ResponseType::AllClear => "AllClear",
+            ResponseType::None => "None",
+        }
+    }
+}
+
+impl std::fmt::Display for ResponseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for ResponseType {
+    type Err = crate::UnknownValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Shelter" => Ok(ResponseType::Shelter),
+            "Evacuate" => Ok(ResponseType::Evacuate),
+            "Prepare" => Ok(ResponseType::Prepare),
+            "Execute" => Ok(ResponseType::Execute),
+            "Avoid" => Ok(ResponseType::Avoid),
+            "Monitor" => Ok(ResponseType::Monitor),
+            "Assess" => Ok(ResponseType::Assess),
+            "AllClear" => Ok(ResponseType::AllClear),
+            "None" => Ok(ResponseType::None),
+            _ => Err(crate::UnknownValueError::new("ResponseType", s)),
+        }
+    }
+}
+
 impl From<crate::v1dot1::ResponseType> for ResponseType {
     fn from(prev: crate::v1dot1::ResponseType) -> Self {
         use crate::v1dot1::ResponseType as Prev;
@@ -0,0 +1,69 @@
+//! Summarizing the structure of an alert.
+
+use super::{Alert, Category, ResponseType};
+
+/// The size and type of an alert at a glance, as returned by [`Alert::summary`].
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct AlertSummary {
+    /// The number of `Info` blocks
+    pub info: usize,
+    /// The number of `Area`s across all `Info` blocks
+    pub areas: usize,
+    /// The number of `Resource`s across all `Info` blocks
+    pub resources: usize,
+    /// The number of polygons across all areas
+    pub polygons: usize,
+    /// The number of circles across all areas
+    pub circles: usize,
+    /// Every category of every `Info` block, without duplicates, in order of first appearance
+    pub categories: Vec<Category>,
+    /// Every response type of every `Info` block, without duplicates, in order of first appearance
+    pub response_types: Vec<ResponseType>,
+}
+
+impl Alert {
+    /// Count the parts of this alert, and collect its categories and response types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let input = include_str!("../../fixtures/v1dot2_appendix_adot2.xml");
+    /// // let input: &str = /* CAP v1.2 appendix A.2 */;
+    /// let alert: oasiscap::v1dot2::Alert = input.parse().unwrap();
+    ///
+    /// let summary = alert.summary();
+    /// assert_eq!(summary.info, 1);
+    /// assert_eq!(summary.areas, 1);
+    /// assert_eq!(summary.polygons, 1);
+    /// assert_eq!(summary.circles, 0);
+    /// assert_eq!(summary.categories, vec![oasiscap::v1dot2::Category::Met]);
+    /// assert_eq!(summary.response_types, vec![oasiscap::v1dot2::ResponseType::Shelter]);
+    /// ```
+    pub fn summary(&self) -> AlertSummary {
+        let mut summary = AlertSummary {
+            info: self.info.len(),
+            ..AlertSummary::default()
+        };
+
+        for info in &self.info {
+            summary.areas += info.areas.len();
+            summary.resources += info.resources.len();
+            for area in &info.areas {
+                summary.polygons += area.polygons.len();
+                summary.circles += area.circles.len();
+            }
+            for category in &info.categories {
+                if !summary.categories.contains(category) {
+                    summary.categories.push(*category);
+                }
+            }
+            for response_type in &info.response_type {
+                if !summary.response_types.contains(response_type) {
+                    summary.response_types.push(*response_type);
+                }
+            }
+        }
+
+        summary
+    }
+}
@@ -88,3 +88,491 @@ fn parse_appendix_adot4() {
         .unwrap();
     assert_eq!(alert.identifier, "KAR0-0306112239-SW");
 }
+
+#[test]
+fn write_xml() {
+    let alert: Alert = include_str!("../../fixtures/v1dot2_appendix_adot2.xml")
+        .parse()
+        .unwrap();
+    let mut output = Vec::new();
+    alert.write_xml(&mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    assert_eq!(output.parse::<Alert>().unwrap(), alert);
+    assert_eq!(output, alert.to_string_with(Default::default()).unwrap());
+}
+
+#[test]
+fn parse_altitude_ceiling() {
+    let alert: Alert = include_str!("../../fixtures/v1dot2_altitude_ceiling.xml")
+        .parse()
+        .unwrap();
+    let area = &alert.info[0].areas[0];
+    assert_eq!(area.altitude, Some(10000.0));
+    assert_eq!(area.ceiling, Some(35000.0));
+
+    let roundtrip: Alert = crate::Alert::from(alert.clone())
+        .to_string()
+        .parse()
+        .unwrap();
+    assert_eq!(roundtrip.info[0].areas[0].altitude, Some(10000.0));
+    assert_eq!(roundtrip.info[0].areas[0].ceiling, Some(35000.0));
+    assert_eq!(roundtrip, alert);
+}
+
+#[test]
+fn parse_padded_numbers() {
+    let alert: Alert = include_str!("../../fixtures/v1dot2_padded_numbers.xml")
+        .parse()
+        .unwrap();
+    assert_eq!(alert.info[0].resources[0].size, Some(12345));
+    let area = &alert.info[0].areas[0];
+    assert_eq!(area.altitude, Some(10000.0));
+    assert_eq!(area.ceiling, Some(35000.0));
+
+    let input = include_str!("../../fixtures/v1dot2_padded_numbers.xml").replacen(
+        "<size> 12345 </size>",
+        "<size>12,345</size>",
+        1,
+    );
+    assert!(input.parse::<Alert>().is_err());
+}
+
+#[test]
+fn extensions() {
+    let input = include_str!("../../fixtures/v1dot2_appendix_adot2.xml")
+        .replacen(
+            "<status>",
+            "<x:pin xmlns:x=\"urn:example\">1234</x:pin><status>",
+            1,
+        )
+        .replacen(
+            "</info>",
+            "<profile xmlns=\"urn:example\"><a>1</a>\n  <b/></profile></info>",
+            1,
+        );
+    let (alert, extensions) = Alert::from_str_preserving_extensions(&input).unwrap();
+    assert_eq!(alert, input.parse::<Alert>().unwrap());
+    assert_eq!(
+        extensions.alert,
+        vec![("{urn:example}pin".to_string(), "1234".to_string())]
+    );
+    assert_eq!(
+        extensions.info,
+        vec![vec![(
+            "{urn:example}profile".to_string(),
+            "<a xmlns=\"urn:example\">1</a><b xmlns=\"urn:example\"></b>".to_string()
+        )]]
+    );
+
+    let mut output = Vec::new();
+    alert
+        .write_xml_with_extensions(&extensions, &mut output)
+        .unwrap();
+    let output = String::from_utf8(output).unwrap();
+    let roundtrip = Alert::from_str_preserving_extensions(&output).unwrap();
+    assert_eq!(roundtrip, (alert.clone(), extensions));
+
+    // Without extensions, the output is unchanged
+    let mut output = Vec::new();
+    alert
+        .write_xml_with_extensions(&Extensions::default(), &mut output)
+        .unwrap();
+    let mut expected = Vec::new();
+    alert.write_xml(&mut expected).unwrap();
+    assert_eq!(output, expected);
+
+    // An XML signature is captured as an extension of the alert
+    let (_, extensions) = Alert::from_str_preserving_extensions(include_str!(
+        "../../fixtures/google-PAAQ-4-mg5a94.xml"
+    ))
+    .unwrap();
+    assert_eq!(
+        extensions.alert,
+        vec![(
+            "{http://www.w3.org/2000/09/xmldsig#}Signature".to_string(),
+            "\n        ...\n    ".to_string()
+        )]
+    );
+}
+
+#[cfg(feature = "sha1")]
+#[test]
+fn compute_digest() {
+    let mut resource = Resource {
+        description: "Text file".into(),
+        mime_type: "text/plain".into(),
+        size: None,
+        uri: None,
+        embedded_content: None,
+        digest: None,
+    };
+
+    // No content means no digest
+    resource.compute_digest();
+    assert_eq!(resource.digest, None);
+
+    // The digest covers the decoded bytes, not the base64 text
+    resource.embedded_content =
+        Some(crate::EmbeddedContent::try_from(String::from("YWJj")).unwrap());
+    resource.compute_digest();
+    assert_eq!(
+        resource.digest,
+        Some("a9993e364706816aba3e25717850c26c9cd0d89d".parse().unwrap())
+    );
+}
+
+#[test]
+fn dominant_category() {
+    let mut alert: Alert = include_str!("../../fixtures/v1dot2_appendix_adot3.xml")
+        .parse()
+        .unwrap();
+    let mut info = alert.info.remove(0);
+
+    // No info means no category
+    assert_eq!(alert.dominant_category(), None);
+
+    // Met and Geo each appear once: Geo wins the tie
+    info.categories = vec![Category::Met, Category::Geo];
+    alert.info.push(info.clone());
+    assert_eq!(alert.dominant_category(), Some(Category::Geo));
+
+    // A second Met info makes Met the most frequent
+    info.categories = vec![Category::Met];
+    alert.info.push(info);
+    assert_eq!(alert.dominant_category(), Some(Category::Met));
+}
+
+#[cfg(feature = "sha1")]
+#[test]
+fn verify_digest() {
+    let mut resource = Resource {
+        description: "Text file".into(),
+        mime_type: "text/plain".into(),
+        size: None,
+        uri: None,
+        embedded_content: Some(crate::EmbeddedContent::from(b"abc".to_vec())),
+        digest: None,
+    };
+
+    // Nothing to compare against
+    assert_eq!(resource.verify_digest(), None);
+
+    resource.compute_digest();
+    assert_eq!(resource.verify_digest(), Some(true));
+
+    // Tampered content no longer matches
+    resource.embedded_content = Some(crate::EmbeddedContent::from(b"abd".to_vec()));
+    assert_eq!(resource.verify_digest(), Some(false));
+
+    // Nothing to verify
+    resource.embedded_content = None;
+    assert_eq!(resource.verify_digest(), None);
+}
+
+#[test]
+fn to_delta() {
+    let previous: Alert = include_str!("../../fixtures/v1dot2_appendix_adot3.xml")
+        .parse()
+        .unwrap();
+
+    let mut current = previous.clone();
+    current.identifier = "TRI13970876.3".parse().unwrap();
+    current.info[0].headline = Some("EQ 3.5 Imperial County CA".into());
+    current.info[0].parameters = current.info[0]
+        .parameters
+        .iter()
+        .filter(|(name, _)| *name != "Quality")
+        .collect();
+    current.note = Some("Magnitude revised".into());
+
+    // Identical alerts have no changes
+    assert!(previous.to_delta(&previous).is_empty());
+
+    let delta = current.to_delta(&previous);
+    assert_eq!(delta.identifier, "TRI13970876.3");
+    assert_eq!(delta.previous_identifier, "TRI13970876.2");
+    assert_eq!(delta.sent, current.sent);
+    assert_eq!(delta.previous_sent, previous.sent);
+    assert_eq!(
+        delta.changes,
+        vec![
+            FieldChange {
+                path: "note".into(),
+                previous: None,
+                current: Some("Magnitude revised".into()),
+            },
+            FieldChange {
+                path: "info.0.headline".into(),
+                previous: Some("EQ 3.4 Imperial County CA".into()),
+                current: Some("EQ 3.5 Imperial County CA".into()),
+            },
+            FieldChange {
+                path: "info.0.parameter.4.valueName".into(),
+                previous: Some("Quality".into()),
+                current: None,
+            },
+            FieldChange {
+                path: "info.0.parameter.4.value".into(),
+                previous: Some("Excellent".into()),
+                current: None,
+            },
+        ]
+    );
+
+    // A new sender is a change, while a new sent time is not
+    let mut resent = previous.clone();
+    resent.sender = "quakes@example.com".parse().unwrap();
+    resent.sent = "2003-06-11T20:57:00-07:00".parse().unwrap();
+    let resent_delta = resent.to_delta(&previous);
+    assert_eq!(resent_delta.sent, resent.sent);
+    assert_eq!(
+        resent_delta.changes,
+        vec![FieldChange {
+            path: "sender".into(),
+            previous: Some(previous.sender.to_string()),
+            current: Some("quakes@example.com".into()),
+        }]
+    );
+
+    // Info::diff reports the same changes relative to the info block
+    let info_changes = current.info[0].diff(&previous.info[0]);
+    assert_eq!(info_changes.len(), 3);
+    assert_eq!(info_changes[0].path, "headline");
+
+    // Deltas round-trip through JSON
+    let json = serde_json::to_string(&delta).unwrap();
+    let roundtrip: AlertDelta = serde_json::from_str(&json).unwrap();
+    assert_eq!(roundtrip, delta);
+}
+
+#[test]
+fn validate_contradictory_scope() {
+    let mut alert: Alert = include_str!("../../fixtures/v1dot2_appendix_adot1.xml")
+        .parse()
+        .unwrap();
+    assert_eq!(alert.scope, Scope::Public);
+    assert_eq!(alert.validate(), Ok(()));
+
+    alert.addresses = Some("ops@example.com".parse().unwrap());
+    assert_eq!(
+        alert.validate(),
+        Err(vec![Conformance::ContradictoryScope("addresses")])
+    );
+
+    // The same addresses are fine on a private alert
+    alert.scope = Scope::Private;
+    assert_eq!(alert.validate(), Ok(()));
+}
+
+#[test]
+fn validate_references() {
+    let mut alert: Alert = include_str!("../../fixtures/v1dot2_appendix_adot1.xml")
+        .parse()
+        .unwrap();
+    assert!(alert.references.is_none());
+
+    for message_type in MessageType::ALL {
+        alert.message_type = *message_type;
+        if *message_type == MessageType::Alert {
+            assert!(!alert.references_required());
+            assert_eq!(alert.validate(), Ok(()));
+        } else {
+            assert!(alert.references_required());
+            assert_eq!(
+                alert.validate(),
+                Err(vec![Conformance::MissingElement("references")])
+            );
+        }
+    }
+
+    // An empty list is no better than none at all
+    alert.message_type = MessageType::Cancel;
+    alert.references = Some(References::new(Vec::new()));
+    assert_eq!(
+        alert.validate(),
+        Err(vec![Conformance::MissingElement("references")])
+    );
+
+    // The appendix A.3 update references its predecessor
+    let alert: Alert = include_str!("../../fixtures/v1dot2_appendix_adot3.xml")
+        .parse()
+        .unwrap();
+    assert_eq!(alert.message_type, MessageType::Update);
+    assert_eq!(alert.validate(), Ok(()));
+}
+
+#[test]
+fn from_str_strict() {
+    let input = include_str!("../../fixtures/v1dot2_appendix_adot2.xml");
+    assert!(Alert::from_str_strict(input).is_ok());
+
+    let input = input
+        .replacen("<polygon>", "<polygon></polygon><polygon>", 1)
+        .replacen("14:57:00-07:00", "21:57:00Z", 1)
+        .replacen("16:00:00-07:00", "16:00:00.5-07:00", 1);
+    // The lenient parser accepts all of these
+    let alert: Alert = input.parse().unwrap();
+    assert_eq!(alert.validate(), Ok(()));
+
+    match Alert::from_str_strict(&input) {
+        Err(StrictParseError::Nonconforming(issues)) => assert_eq!(
+            issues,
+            vec![
+                Conformance::AlphabeticTimeZone("sent"),
+                Conformance::FractionalSeconds("expires"),
+                Conformance::EmptyPolygon,
+            ]
+        ),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    let mut alert = alert;
+    alert.info[0].categories.clear();
+    assert_eq!(
+        alert.validate(),
+        Err(vec![Conformance::MissingElement("category")])
+    );
+}
+
+#[test]
+fn chain_example() {
+    let chain = Alert::chain_example();
+    assert_eq!(chain.len(), 3);
+
+    for alert in &chain {
+        assert_eq!(alert.validate(), Ok(()));
+        assert_eq!(alert.sender, chain[0].sender);
+
+        // Each message survives XML serialization
+        let roundtrip: Alert = crate::Alert::from(alert.clone())
+            .to_string()
+            .parse()
+            .unwrap();
+        assert_eq!(&roundtrip, alert);
+    }
+
+    // Each message references every prior message
+    for (i, alert) in chain.iter().enumerate() {
+        let references = alert
+            .references
+            .as_ref()
+            .map(|r| r.as_slice())
+            .unwrap_or_default();
+        assert_eq!(references.len(), i);
+        for (reference, prior) in references.iter().zip(&chain) {
+            assert_eq!(reference.sender, prior.sender);
+            assert_eq!(reference.identifier, prior.identifier);
+            assert_eq!(reference.sent, prior.sent);
+            assert!(prior.sent < alert.sent);
+        }
+    }
+}
+
+#[test]
+fn set_content() {
+    let mut resource = Resource {
+        description: "Map".into(),
+        mime_type: "image/png".into(),
+        size: Some(12345),
+        uri: None,
+        embedded_content: None,
+        digest: None,
+    };
+
+    let content = crate::EmbeddedContent::try_from(String::from("iVBORw0K\nGgo=")).unwrap();
+    resource.set_content(content);
+    assert_eq!(resource.size, Some(8));
+    assert_eq!(
+        resource.size,
+        resource.embedded_content.as_ref().map(|c| c.len() as u64)
+    );
+}
+
+#[test]
+fn info_for_language() {
+    let mut alert: Alert = include_str!("../../fixtures/v1dot2_appendix_adot4.xml")
+        .parse()
+        .unwrap();
+
+    // An unspecified language is treated as en-US
+    alert.info[0].language = Default::default();
+    assert_eq!(alert.info_for_language("en").len(), 1);
+    assert_eq!(alert.info_for_language("en-US").len(), 1);
+
+    // Prefixes only match at subtag boundaries
+    assert_eq!(alert.info_for_language("es-U").len(), 1);
+    assert_eq!(
+        alert.info_for_language("es-U")[0].language.as_option_str(),
+        None
+    );
+    assert_eq!(
+        alert.info_for_language("es-us")[0].language.as_option_str(),
+        Some("es-US")
+    );
+
+    // Later blocks in the same language are all returned
+    let mut more = alert.info[1].clone();
+    more.headline = None;
+    alert.info.push(more);
+    assert_eq!(alert.info_for_language("es").len(), 2);
+}
+
+#[test]
+fn active_window() {
+    let mut alert: Alert = include_str!("../../fixtures/v1dot2_appendix_adot1.xml")
+        .parse()
+        .unwrap();
+
+    // With neither `effective` nor `expires`, the window opens at `sent` and never closes
+    assert_eq!(alert.info[0].active_window(alert.sent), (alert.sent, None));
+
+    let effective: crate::DateTime = "2003-04-02T15:00:00-05:00".parse().unwrap();
+    let expires: crate::DateTime = "2003-04-03T15:00:00-05:00".parse().unwrap();
+    alert.info[0].effective = Some(effective);
+    alert.info[0].expires = Some(expires);
+    assert_eq!(
+        alert.info[0].active_window(alert.sent),
+        (effective, Some(expires))
+    );
+}
+
+#[test]
+fn enum_strings() {
+    // Display and FromStr use the CAP spelling
+    assert_eq!(Category::CBRNE.to_string(), "CBRNE");
+    assert_eq!("Met".parse::<Category>().unwrap(), Category::Met);
+    assert_eq!(ResponseType::AllClear.to_string(), "AllClear");
+    assert_eq!(
+        "AllClear".parse::<ResponseType>(),
+        Ok(ResponseType::AllClear)
+    );
+    assert_eq!(MessageType::Ack.to_string(), "Ack");
+    assert_eq!("Draft".parse::<Status>(), Ok(Status::Draft));
+    assert_eq!("Restricted".parse::<Scope>(), Ok(Scope::Restricted));
+    assert_eq!("Immediate".parse::<Urgency>(), Ok(Urgency::Immediate));
+    assert_eq!("Extreme".parse::<Severity>(), Ok(Severity::Extreme));
+    assert_eq!("Very Likely".parse::<Certainty>(), Ok(Certainty::Likely));
+
+    // Parsing is case-sensitive, like the schema
+    assert!("met".parse::<Category>().is_err());
+    assert!("All Clear".parse::<ResponseType>().is_err());
+
+    // v1.0 Certainty has its own spelling
+    assert_eq!(
+        crate::v1dot0::Certainty::VeryLikely.to_string(),
+        "Very Likely"
+    );
+    assert_eq!(
+        "Very Likely".parse::<crate::v1dot0::Certainty>(),
+        Ok(crate::v1dot0::Certainty::VeryLikely)
+    );
+}
+
+#[test]
+fn enum_variants() {
+    assert_eq!(ResponseType::ALL.len(), 9);
+
+    for value in ResponseType::ALL {
+        assert_eq!(value.to_string().parse::<ResponseType>().unwrap(), *value);
+    }
+}
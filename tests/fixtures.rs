@@ -52,6 +52,14 @@ fn test_proto(name: &str, alert: &oasiscap::Alert) {
     let bytes = proto.encode_length_delimited_to_vec();
     let reproto = oasiscap::protobuf::Alert::decode_length_delimited(bytes.as_slice())
         .expect("parse protobuf");
+    #[cfg(feature = "protobuf-json")]
+    assert_eq!(
+        oasiscap::protobuf::Alert::from_json(&reproto.to_json()).expect("from JSON"),
+        reproto,
+        "mismatch roundtripping {} through protobuf JSON",
+        name
+    );
+
     let roundtrip = oasiscap::Alert::try_from(reproto).expect("from proto");
     assert_eq!(
         &roundtrip, alert,
@@ -75,6 +83,27 @@ fn test_xml_roundtrip(name: &str, alert: &oasiscap::Alert) {
         "mismatch roundtripping {} through XML",
         name
     );
+
+    let pretty = alert.to_string_pretty().expect("write pretty XML");
+    let roundtrip: oasiscap::Alert = pretty.parse().expect("parse pretty XML");
+    assert_eq!(
+        alert, &roundtrip,
+        "mismatch roundtripping {} through pretty XML",
+        name
+    );
+
+    let unprefixed = alert
+        .to_string_with(oasiscap::XmlOutputOptions {
+            default_namespace: true,
+            indent: false,
+        })
+        .expect("write unprefixed XML");
+    let roundtrip: oasiscap::Alert = unprefixed.parse().expect("parse unprefixed XML");
+    assert_eq!(
+        alert, &roundtrip,
+        "mismatch roundtripping {} through unprefixed XML",
+        name
+    );
 }
 
 #[test]